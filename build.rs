@@ -0,0 +1,39 @@
+use std::{env, fs, path::Path};
+
+// Writes `yYYYY_registry.rs` into OUT_DIR for every `src/solvers/yYYYY/mod.rs`, calling
+// `register!` with each `pub mod dayN;` that module declares, so a day is only listed once
+fn main() {
+    let out_dir = env::var("OUT_DIR").unwrap();
+    println!("cargo:rerun-if-changed=src/solvers");
+    for entry in fs::read_dir("src/solvers").unwrap() {
+        let year_dir = entry.unwrap().path();
+        let Some(year) = year_dir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .filter(|name| name.starts_with('y'))
+        else {
+            continue;
+        };
+        let mod_file = year_dir.join("mod.rs");
+        if !mod_file.is_file() {
+            continue;
+        }
+        println!("cargo:rerun-if-changed={}", mod_file.display());
+
+        let source = fs::read_to_string(&mod_file).unwrap();
+        let mut days: Vec<(u8, &str)> = source
+            .lines()
+            .filter_map(|line| {
+                let module = line.trim().strip_prefix("pub mod ")?.strip_suffix(';')?;
+                Some((module.strip_prefix("day")?.parse().ok()?, module))
+            })
+            .collect();
+        days.sort();
+        let modules: Vec<&str> = days.iter().map(|&(_, module)| module).collect();
+        fs::write(
+            Path::new(&out_dir).join(format!("{year}_registry.rs")),
+            format!("register!({});\n", modules.join(", ")),
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,359 @@
+use std::{
+    cmp::max,
+    fs::File,
+    io::{self, BufWriter, Write},
+    process::ExitCode,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use advent_of_code_2024::{
+    diagnostics,
+    isolate::{self, Outcome},
+    output::{escape_csv, escape_json},
+};
+use clap::ValueEnum;
+
+use crate::{
+    check_params, find_test_files, load_inputs, parse_args, solve_part, solvers, CliArgs,
+    DayInputPair, Params, ParseDayInputPairError, Solver,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum BenchFormat {
+    Json,
+    Csv,
+}
+
+struct Stats {
+    min: Duration,
+    median: Duration,
+    mean: Duration,
+    p95: Duration,
+    std_dev: Duration,
+}
+
+impl Stats {
+    fn from_samples(samples: &mut [Duration]) -> Stats {
+        samples.sort();
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+        // Nearest-rank percentile
+        let p95 = samples[(n * 95).div_ceil(100) - 1];
+        let mean_secs = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean_secs).powi(2))
+            .sum::<f64>()
+            / n as f64;
+        Stats {
+            min: samples[0],
+            median,
+            mean: Duration::from_secs_f64(mean_secs),
+            p95,
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+struct BenchResult {
+    year: u16,
+    day: u8,
+    name: String,
+    part: u8,
+    iterations: usize,
+    stats: Stats,
+}
+
+fn bench_day(args: &CliArgs, input_pair: &DayInputPair, results: &mut Vec<BenchResult>) -> bool {
+    let Some(registration) = solvers::get(input_pair.year, input_pair.day) else {
+        eprintln!(
+            "Could not find solver for day {} of {}",
+            input_pair.day, input_pair.year
+        );
+        return false;
+    };
+    let solver: Arc<dyn Solver> = Arc::from((registration.solver)());
+    if !args.all {
+        if let Err(param_err) = check_params(
+            args,
+            [solver.as_ref()],
+            &format!("day {} takes no parameters", input_pair.day),
+        ) {
+            eprintln!("{param_err}");
+            return false;
+        }
+    }
+    let params = Params::new(solver.params(), &args.params);
+    let mut succeeded = true;
+    for part in [1, 2] {
+        if !args.part.includes(part) || !registration.supports(part) {
+            continue;
+        }
+        let mut samples: Vec<Duration> = Vec::with_capacity(args.iterations);
+        for i in 0..args.warmup + args.iterations {
+            // Timed inside the run, so starting a thread for `--timeout` isn't measured
+            let (solver, input, params) = (
+                Arc::clone(&solver),
+                Arc::clone(&input_pair.input),
+                params.clone(),
+            );
+            let outcome = isolate::run(args.timeout(), move || {
+                let start = Instant::now();
+                let result = solve_part(solver.as_ref(), part, &input, &params);
+                (result, start.elapsed())
+            });
+            let (failure, elapsed) = match outcome {
+                Outcome::Finished((Ok(_), elapsed)) => (None, elapsed),
+                Outcome::Finished((Err(solve_err), _)) => (
+                    Some(solve_err.with_day(input_pair.day).to_string()),
+                    Duration::ZERO,
+                ),
+                _ => (outcome.failure(), Duration::ZERO),
+            };
+            if let Some(failure) = failure {
+                eprintln!(
+                    "Skipping day {} input {} part {part}, it failed: {failure}",
+                    input_pair.day, input_pair.name
+                );
+                succeeded = false;
+                break;
+            }
+            if i >= args.warmup {
+                samples.push(elapsed);
+            }
+        }
+        if samples.len() == args.iterations {
+            results.push(BenchResult {
+                year: input_pair.year,
+                day: input_pair.day,
+                name: input_pair.name.clone(),
+                part,
+                iterations: args.iterations,
+                stats: Stats::from_samples(&mut samples),
+            });
+        }
+    }
+    succeeded
+}
+
+fn print_results(results: &[BenchResult]) {
+    let columns: Vec<[String; 5]> = results
+        .iter()
+        .map(|result| {
+            let stats = &result.stats;
+            [
+                stats.min,
+                stats.median,
+                stats.mean,
+                stats.p95,
+                stats.std_dev,
+            ]
+            .map(|time| format!("{time:.2?}"))
+        })
+        .collect();
+    let width = columns
+        .iter()
+        .flatten()
+        .map(|time| time.len())
+        .fold("Median".len(), max);
+    let name_width = results
+        .iter()
+        .map(|result| result.name.len())
+        .fold("Input".len(), max);
+
+    println!(
+        "{:>3}  {:<name_width$}  {:>4}  {:>width$}  {:>width$}  {:>width$}  {:>width$}  {:>width$}",
+        "Day", "Input", "Part", "Min", "Median", "Mean", "p95", "StdDev"
+    );
+    for (result, [min, median, mean, p95, std_dev]) in results.iter().zip(columns) {
+        println!(
+            "{:>3}  {:<name_width$}  {:>4}  {min:>width$}  {median:>width$}  {mean:>width$}  {p95:>width$}  {std_dev:>width$}",
+            result.day, result.name, result.part
+        );
+    }
+}
+
+// Times are written in nanoseconds
+fn write_json(results: &[BenchResult], writer: &mut impl Write) -> io::Result<()> {
+    writeln!(writer, "[")?;
+    for (i, result) in results.iter().enumerate() {
+        let stats = &result.stats;
+        write!(
+            writer,
+            "  {{\"year\": {}, \"day\": {}, \"input\": \"{}\", \"part\": {}, \"iterations\": {}, \"min_ns\": {}, \"median_ns\": {}, \"mean_ns\": {}, \"p95_ns\": {}, \"std_dev_ns\": {}}}",
+            result.year,
+            result.day,
+            escape_json(&result.name),
+            result.part,
+            result.iterations,
+            stats.min.as_nanos(),
+            stats.median.as_nanos(),
+            stats.mean.as_nanos(),
+            stats.p95.as_nanos(),
+            stats.std_dev.as_nanos()
+        )?;
+        writeln!(writer, "{}", if i + 1 < results.len() { "," } else { "" })?;
+    }
+    writeln!(writer, "]")
+}
+
+fn write_csv(results: &[BenchResult], writer: &mut impl Write) -> io::Result<()> {
+    writeln!(
+        writer,
+        "year,day,input,part,iterations,min_ns,median_ns,mean_ns,p95_ns,std_dev_ns"
+    )?;
+    for result in results {
+        let stats = &result.stats;
+        writeln!(
+            writer,
+            "{},{},{},{},{},{},{},{},{},{}",
+            result.year,
+            result.day,
+            escape_csv(&result.name),
+            result.part,
+            result.iterations,
+            stats.min.as_nanos(),
+            stats.median.as_nanos(),
+            stats.mean.as_nanos(),
+            stats.p95.as_nanos(),
+            stats.std_dev.as_nanos()
+        )?;
+    }
+    Ok(())
+}
+
+fn write_results(args: &CliArgs, results: &[BenchResult]) -> io::Result<()> {
+    let Some(path) = &args.bench_output else {
+        return Ok(());
+    };
+    let mut writer = BufWriter::new(File::create(path)?);
+    match args.bench_format {
+        BenchFormat::Json => write_json(results, &mut writer)?,
+        BenchFormat::Csv => write_csv(results, &mut writer)?,
+    }
+    writer.flush()
+}
+
+fn collect_inputs(args: &CliArgs) -> Result<Vec<DayInputPair>, ParseDayInputPairError> {
+    if !args.all {
+        return parse_args(args);
+    }
+    let day_dir_map = find_test_files(args)?;
+    let mut days: Vec<u8> = day_dir_map
+        .keys()
+        .copied()
+        .filter(|day| solvers::get(args.year(), *day).is_some())
+        .collect();
+    days.sort();
+    let mut input_pairs: Vec<DayInputPair> = Vec::new();
+    for day in days {
+        input_pairs.extend(load_inputs(args, day, day_dir_map.get(&day).unwrap())?);
+    }
+    Ok(input_pairs)
+}
+
+pub fn run(args: &CliArgs) -> ExitCode {
+    if args.iterations == 0 {
+        eprintln!("At least one iteration is needed to benchmark");
+        return ExitCode::FAILURE;
+    }
+    let inputs = match collect_inputs(args) {
+        Ok(inputs) => inputs,
+        Err(parse_err) => {
+            eprintln!("Encountered error while parsing input: {}", parse_err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // Failures are reported once per part instead of through the default hook, and diagnostics
+    // would be repeated on every iteration
+    diagnostics::set_enabled(false);
+    let mut results: Vec<BenchResult> = Vec::new();
+    let mut succeeded = true;
+    isolate::quietly(|| {
+        for input_pair in &inputs {
+            succeeded &= bench_day(args, input_pair, &mut results);
+        }
+    });
+
+    print_results(&results);
+    if let Err(io_err) = write_results(args, &results) {
+        eprintln!("Encountered error while writing benchmark results: {io_err}");
+        return ExitCode::FAILURE;
+    }
+    if succeeded {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: impl IntoIterator<Item = u64>) -> Vec<Duration> {
+        values.into_iter().map(Duration::from_millis).collect()
+    }
+
+    // The mean and standard deviation go through f64 seconds, so they're only checked to the µs
+    fn assert_close(actual: Duration, expected: Duration) {
+        assert!(
+            actual.abs_diff(expected) < Duration::from_micros(1),
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn a_single_sample_is_every_statistic() {
+        let stats = Stats::from_samples(&mut millis([7]));
+        assert_eq!(stats.min, Duration::from_millis(7));
+        assert_eq!(stats.median, Duration::from_millis(7));
+        assert_eq!(stats.p95, Duration::from_millis(7));
+        assert_close(stats.mean, Duration::from_millis(7));
+        assert_close(stats.std_dev, Duration::ZERO);
+    }
+
+    #[test]
+    fn odd_counts_take_the_middle_sample() {
+        let stats = Stats::from_samples(&mut millis([9, 1, 5, 3, 7]));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_eq!(stats.p95, Duration::from_millis(9));
+    }
+
+    #[test]
+    fn ten_samples() {
+        let stats = Stats::from_samples(&mut millis((1..=10).rev()));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        // Even counts average the two middle samples
+        assert_eq!(stats.median, Duration::from_micros(5500));
+        // The 95th percentile of 10 samples is rank ceil(9.5) = 10
+        assert_eq!(stats.p95, Duration::from_millis(10));
+        assert_close(stats.mean, Duration::from_micros(5500));
+        // The population deviation, sqrt(8.25) ms
+        assert_close(
+            stats.std_dev,
+            Duration::from_secs_f64(8.25f64.sqrt() / 1000.0),
+        );
+    }
+
+    #[test]
+    fn twenty_samples() {
+        let stats = Stats::from_samples(&mut millis((1..=20).map(|i| (i * 7) % 20 + 1)));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(10500));
+        // Rank 19, so the slowest sample is left out
+        assert_eq!(stats.p95, Duration::from_millis(19));
+        assert_close(stats.mean, Duration::from_micros(10500));
+        assert_close(
+            stats.std_dev,
+            Duration::from_secs_f64(33.25f64.sqrt() / 1000.0),
+        );
+    }
+}
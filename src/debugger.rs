@@ -0,0 +1,324 @@
+use std::{
+    collections::BTreeSet,
+    fs::File,
+    io::{self, BufRead, BufWriter, Write},
+    path::Path,
+    process::ExitCode,
+};
+
+use advent_of_code_2024::{
+    disassemble::{self, Instruction},
+    vm::{Registers, TraceEntry, Vm},
+};
+use clap::ValueEnum;
+
+use crate::{read_input_file, CliArgs};
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum TraceFormat {
+    Text,
+    Json,
+}
+
+// Reads a day 17 input, with `register_a` replacing the value it starts with
+fn load_vm(args: &CliArgs, path: &Path, register_a: Option<usize>) -> Option<Vm> {
+    let input_pair = match read_input_file(2024, 17, path, args.warn_input) {
+        Ok(input_pair) => input_pair,
+        Err(parse_err) => {
+            eprintln!("Encountered error while parsing input: {}", parse_err);
+            return None;
+        }
+    };
+    match Vm::parse(&input_pair.input) {
+        Ok(mut vm) => {
+            if let Some(a) = register_a {
+                vm.registers.a = a;
+            }
+            Some(vm)
+        }
+        Err(solve_err) => {
+            eprintln!("Encountered error while parsing the program: {solve_err}");
+            None
+        }
+    }
+}
+
+pub fn print_disassembly(args: &CliArgs, path: &Path) -> ExitCode {
+    let Some(vm) = load_vm(args, path, None) else {
+        return ExitCode::FAILURE;
+    };
+    let Registers { a, b, c } = vm.registers;
+    println!("Registers: A = {a}, B = {b}, C = {c}");
+    println!("Disassembly:");
+    for instruction in disassemble::disassemble(vm.program()) {
+        println!("{instruction}");
+    }
+    println!("Decompiled:");
+    match disassemble::decompile(vm.program()) {
+        Ok(lines) => {
+            for line in lines {
+                println!("{line}");
+            }
+            ExitCode::SUCCESS
+        }
+        Err(solve_err) => {
+            eprintln!("Could not decompile the program: {}", solve_err.message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn format_registers(registers: &Registers) -> String {
+    format!("A={} B={} C={}", registers.a, registers.b, registers.c)
+}
+
+fn format_entry(entry: &TraceEntry) -> String {
+    let instruction = Instruction {
+        address: entry.ip,
+        opcode: entry.opcode,
+        operand: entry.operand,
+    };
+    let text = format!("{} {}", entry.opcode, instruction.operand_text());
+    let mut line = format!(
+        "{:>6}  {:>3}: {:<14}{} -> {}",
+        entry.step,
+        entry.ip,
+        text.trim_end(),
+        format_registers(&entry.before),
+        format_registers(&entry.after)
+    );
+    if let Some(value) = entry.output {
+        line.push_str(&format!("  output {value}"));
+    }
+    line
+}
+
+fn json_registers(registers: &Registers) -> String {
+    format!(
+        "{{\"a\": {}, \"b\": {}, \"c\": {}}}",
+        registers.a, registers.b, registers.c
+    )
+}
+
+// Entries are written as they execute, so the trace of a program that never halts still shows
+// its first `max_steps` instructions
+fn write_trace(
+    vm: &mut Vm,
+    format: TraceFormat,
+    max_steps: usize,
+    writer: &mut impl Write,
+) -> io::Result<Result<(), String>> {
+    if format == TraceFormat::Json {
+        write!(writer, "[")?;
+    }
+    let mut result = Ok(());
+    while !vm.is_halted() {
+        if vm.steps() >= max_steps {
+            result = Err(format!("stopped after {max_steps} steps"));
+            break;
+        }
+        let entry = match vm.step_traced() {
+            Ok(Some(entry)) => entry,
+            Ok(None) => break,
+            Err(solve_err) => {
+                result = Err(solve_err.message);
+                break;
+            }
+        };
+        match format {
+            TraceFormat::Text => writeln!(writer, "{}", format_entry(&entry))?,
+            TraceFormat::Json => {
+                let output = entry
+                    .output
+                    .map(|value| value.to_string())
+                    .unwrap_or(String::from("null"));
+                write!(
+                    writer,
+                    "{}\n  {{\"step\": {}, \"ip\": {}, \"opcode\": \"{}\", \"operand\": {}, \"before\": {}, \"after\": {}, \"output\": {}}}",
+                    if entry.step == 0 { "" } else { "," },
+                    entry.step,
+                    entry.ip,
+                    entry.opcode,
+                    entry.operand,
+                    json_registers(&entry.before),
+                    json_registers(&entry.after),
+                    output
+                )?;
+            }
+        }
+    }
+    if format == TraceFormat::Json {
+        writeln!(writer, "\n]")?;
+    }
+    writer.flush()?;
+    Ok(result)
+}
+
+pub fn trace(
+    args: &CliArgs,
+    path: &Path,
+    register_a: Option<usize>,
+    format: TraceFormat,
+    trace_output: Option<&Path>,
+    max_steps: usize,
+) -> ExitCode {
+    let Some(mut vm) = load_vm(args, path, register_a) else {
+        return ExitCode::FAILURE;
+    };
+    let written = match trace_output {
+        Some(trace_path) => File::create(trace_path)
+            .and_then(|file| write_trace(&mut vm, format, max_steps, &mut BufWriter::new(file))),
+        None => write_trace(&mut vm, format, max_steps, &mut io::stdout().lock()),
+    };
+    match written {
+        Ok(Ok(())) => ExitCode::SUCCESS,
+        // e.g. piped into `head`
+        Err(io_err) if io_err.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Ok(Err(message)) => {
+            eprintln!("The trace ended early: {message}");
+            ExitCode::FAILURE
+        }
+        Err(io_err) => {
+            eprintln!("Encountered error while writing the trace: {io_err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+const DEBUG_HELP: &str = "Commands:
+  s, step [N]     execute N instructions, 1 by default
+  c, continue     run until a breakpoint, the program halts or --max-steps is reached
+  b, break IP     stop before the instruction at IP
+  d, delete IP    remove a breakpoint
+  r, regs         show the registers and instruction pointer
+  o, output       show the output so far
+  l, list         show the program with the current instruction marked
+  h, help         show this help
+  q, quit";
+
+fn print_location(vm: &Vm) {
+    if vm.is_halted() {
+        println!("Halted at ip {}", vm.ip());
+    } else {
+        println!("ip {}, {}", vm.ip(), format_registers(&vm.registers));
+    }
+}
+
+// Executes one instruction, printing it like the text trace. Returns false once it can't go on
+fn debug_step(vm: &mut Vm) -> bool {
+    match vm.step_traced() {
+        Ok(Some(entry)) => {
+            println!("{}", format_entry(&entry));
+            true
+        }
+        Ok(None) => {
+            print_location(vm);
+            false
+        }
+        Err(solve_err) => {
+            println!("Error: {}", solve_err.message);
+            false
+        }
+    }
+}
+
+fn continue_to_breakpoint(vm: &mut Vm, breakpoints: &BTreeSet<usize>, max_steps: usize) {
+    let start = vm.steps();
+    loop {
+        if let Err(solve_err) = vm.step() {
+            println!("Error: {}", solve_err.message);
+            return;
+        }
+        if vm.is_halted() {
+            break;
+        }
+        if breakpoints.contains(&vm.ip()) {
+            println!("Breakpoint at ip {}", vm.ip());
+            break;
+        }
+        if vm.steps() - start >= max_steps {
+            println!("Stopped after {max_steps} steps");
+            break;
+        }
+    }
+    print_location(vm);
+}
+
+pub fn debug(
+    args: &CliArgs,
+    path: &Path,
+    register_a: Option<usize>,
+    breakpoints: &[usize],
+    max_steps: usize,
+) -> ExitCode {
+    if path == Path::new("-") {
+        eprintln!("The program can't be read from stdin, which is used for debugger commands");
+        return ExitCode::FAILURE;
+    }
+    let Some(mut vm) = load_vm(args, path, register_a) else {
+        return ExitCode::FAILURE;
+    };
+    let mut breakpoints: BTreeSet<usize> = breakpoints.iter().copied().collect();
+    let instructions = disassemble::disassemble(vm.program());
+    println!("{DEBUG_HELP}");
+    print_location(&vm);
+
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        print!("(vm) ");
+        let _ = io::stdout().flush();
+        let Some(Ok(line)) = lines.next() else {
+            break;
+        };
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let argument = words.get(1).map(|word| word.parse::<usize>());
+        match (words.first().copied(), argument) {
+            (None, _) => (),
+            (Some("s" | "step"), None) => {
+                debug_step(&mut vm);
+            }
+            (Some("s" | "step"), Some(Ok(count))) => {
+                for _ in 0..count {
+                    if !debug_step(&mut vm) {
+                        break;
+                    }
+                }
+            }
+            (Some("c" | "continue"), _) => continue_to_breakpoint(&mut vm, &breakpoints, max_steps),
+            (Some("b" | "break"), Some(Ok(ip))) => {
+                breakpoints.insert(ip);
+                let all: Vec<String> = breakpoints.iter().map(usize::to_string).collect();
+                println!("Breakpoints: {}", all.join(", "));
+            }
+            (Some("d" | "delete"), Some(Ok(ip))) => {
+                if !breakpoints.remove(&ip) {
+                    println!("No breakpoint at ip {ip}");
+                }
+            }
+            (Some("r" | "regs"), _) => print_location(&vm),
+            (Some("o" | "output"), _) => {
+                let output: Vec<String> = vm.output().iter().map(u8::to_string).collect();
+                println!("Output: {}", output.join(","));
+            }
+            (Some("l" | "list"), _) => {
+                for instruction in &instructions {
+                    let marker = if instruction.address == vm.ip() {
+                        "=>"
+                    } else {
+                        "  "
+                    };
+                    let breakpoint = if breakpoints.contains(&instruction.address) {
+                        "*"
+                    } else {
+                        " "
+                    };
+                    println!("{marker}{breakpoint}{instruction}");
+                }
+            }
+            (Some("h" | "help"), _) => println!("{DEBUG_HELP}"),
+            (Some("q" | "quit"), _) => break,
+            _ => println!("Unknown command '{line}', try 'help'"),
+        }
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,15 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// Solvers report anything that is not part of the answer, such as day 14's picture, through here
+// so that it goes to stderr and never mixes with the answers on stdout
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn emit(text: &str) {
+    if ENABLED.load(Ordering::Relaxed) {
+        eprintln!("{text}");
+    }
+}
@@ -0,0 +1,312 @@
+use std::fmt::Display;
+
+use crate::{vm::Opcode, SolveError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub address: usize,
+    pub opcode: Opcode,
+    pub operand: u8,
+}
+
+impl Instruction {
+    // The operand as the instruction reads it, e.g. "a" for combo operand 4
+    pub fn operand_text(&self) -> String {
+        match (self.opcode, self.operand) {
+            (Opcode::Bxc, _) => String::new(),
+            (opcode, 4..=6) if opcode.takes_combo() => {
+                ["a", "b", "c"][self.operand as usize - 4].to_string()
+            }
+            (opcode, 7) if opcode.takes_combo() => String::from("reserved"),
+            (_, operand) => operand.to_string(),
+        }
+    }
+
+    // What the instruction does in pseudocode, e.g. "b = a % 8"
+    pub fn effect(&self) -> String {
+        let operand = self.operand_text();
+        match self.opcode {
+            Opcode::Adv => format!("a = a >> {operand}"),
+            Opcode::Bxl => format!("b = b ^ {operand}"),
+            Opcode::Bst => format!("b = {operand} % 8"),
+            Opcode::Jnz => format!("if a != 0 goto {operand}"),
+            Opcode::Bxc => String::from("b = b ^ c"),
+            Opcode::Out => format!("output {operand} % 8"),
+            Opcode::Bdv => format!("b = a >> {operand}"),
+            Opcode::Cdv => format!("c = a >> {operand}"),
+        }
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = format!("{} {}", self.opcode, self.operand_text());
+        write!(
+            f,
+            "{:>3}: {:<14}{}",
+            self.address,
+            text.trim_end(),
+            self.effect()
+        )
+    }
+}
+
+// A last opcode without an operand is left out, as the program halts before reaching it
+pub fn disassemble(program: &[u8]) -> Vec<Instruction> {
+    program
+        .chunks_exact(2)
+        .enumerate()
+        .map(|(i, pair)| Instruction {
+            address: i * 2,
+            opcode: Opcode::from_bits(pair[0]),
+            operand: pair[1],
+        })
+        .collect()
+}
+
+// Register values in terms of what they held at the start of the loop
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Expr {
+    Register(char),
+    Literal(usize),
+    Binary(Box<Expr>, &'static str, Box<Expr>),
+}
+
+impl Expr {
+    fn binary(left: Expr, op: &'static str, right: Expr) -> Expr {
+        Expr::Binary(Box::new(left), op, Box::new(right))
+    }
+
+    // Shifting an already shifted value by a constant adds up, e.g. `(a >> 1) >> 2` is `a >> 3`
+    fn shifted(self, amount: Expr) -> Expr {
+        if let (Expr::Binary(left, ">>", right), Expr::Literal(more)) = (&self, &amount) {
+            if let Expr::Literal(shift) = **right {
+                return Expr::Binary(left.clone(), ">>", Box::new(Expr::Literal(shift + more)));
+            }
+        }
+        Expr::binary(self, ">>", amount)
+    }
+
+    // Output and `bst` both keep the low 3 bits, which only needs doing once
+    fn low_bits(self) -> Expr {
+        match self {
+            Expr::Binary(_, "%", _) => self,
+            Expr::Literal(value) => Expr::Literal(value % 8),
+            _ => Expr::binary(self, "%", Expr::Literal(8)),
+        }
+    }
+
+    fn reads(&self, register: char) -> bool {
+        match self {
+            Expr::Register(name) => *name == register,
+            Expr::Literal(_) => false,
+            Expr::Binary(left, _, right) => left.reads(register) || right.reads(register),
+        }
+    }
+
+    fn fmt_nested(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expr::Binary(..) => write!(f, "({self})"),
+            _ => write!(f, "{self}"),
+        }
+    }
+}
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expr::Register(name) => write!(f, "{name}"),
+            Expr::Literal(value) => write!(f, "{value}"),
+            Expr::Binary(left, op, right) => {
+                left.fmt_nested(f)?;
+                write!(f, " {op} ")?;
+                right.fmt_nested(f)
+            }
+        }
+    }
+}
+
+// One pass through a program that is a single loop ending in `jnz 0`, like every puzzle input
+struct Loop {
+    outputs: Vec<Expr>,
+    registers: [Expr; 3],
+}
+
+fn run_symbolically(program: &[u8]) -> Result<Loop, SolveError> {
+    let instructions = disassemble(program);
+    let Some((last, body)) = instructions.split_last() else {
+        return Err(SolveError::new("the program is empty"));
+    };
+    if !program.len().is_multiple_of(2)
+        || last.opcode != Opcode::Jnz
+        || last.operand != 0
+        || body
+            .iter()
+            .any(|instruction| instruction.opcode == Opcode::Jnz)
+    {
+        return Err(SolveError::new(
+            "the program is not a single loop ending in 'jnz 0'",
+        ));
+    }
+
+    let [mut a, mut b, mut c] = ['a', 'b', 'c'].map(Expr::Register);
+    let mut outputs: Vec<Expr> = Vec::new();
+    for instruction in body {
+        let combo = || match instruction.operand {
+            0..=3 => Ok(Expr::Literal(instruction.operand as usize)),
+            4 => Ok(a.clone()),
+            5 => Ok(b.clone()),
+            6 => Ok(c.clone()),
+            _ => Err(SolveError::new(format!(
+                "combo operand 7 at instruction {} is reserved",
+                instruction.address
+            ))),
+        };
+        match instruction.opcode {
+            Opcode::Adv => a = a.clone().shifted(combo()?),
+            Opcode::Bxl => {
+                b = Expr::binary(b.clone(), "^", Expr::Literal(instruction.operand as usize))
+            }
+            Opcode::Bst => b = combo()?.low_bits(),
+            Opcode::Jnz => unreachable!("the loop body has no jumps"),
+            Opcode::Bxc => b = Expr::binary(b.clone(), "^", c.clone()),
+            Opcode::Out => outputs.push(combo()?.low_bits()),
+            Opcode::Bdv => b = a.clone().shifted(combo()?),
+            Opcode::Cdv => c = a.clone().shifted(combo()?),
+        }
+    }
+    Ok(Loop {
+        outputs,
+        registers: [a, b, c],
+    })
+}
+
+// Turns a single loop program into an expression per output and the register updates carried
+// into the next iteration
+pub fn decompile(program: &[u8]) -> Result<Vec<String>, SolveError> {
+    let Loop { outputs, registers } = run_symbolically(program)?;
+    let mut lines: Vec<String> = Vec::from([String::from("do {")]);
+    lines.extend(outputs.iter().map(|output| format!("    output {output}")));
+    // b and c only need carrying over when the next iteration reads them before writing them
+    let carried = ['a', 'b', 'c']
+        .into_iter()
+        .zip(&registers)
+        .filter(|(name, value)| {
+            **value != Expr::Register(*name)
+                && (*name == 'a'
+                    || outputs.iter().any(|output| output.reads(*name))
+                    || registers.iter().any(|value| value.reads(*name)))
+        });
+    lines.extend(carried.map(|(name, value)| format!("    {name} = {value}")));
+    lines.push(String::from("} while a != 0"));
+    Ok(lines)
+}
+
+// A loop that shifts `shift` bits out of a and outputs `outputs` values each iteration, which
+// only depend on a. Working back from the last output then fixes `shift` bits of a at a time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoopShape {
+    pub shift: u32,
+    pub outputs: usize,
+}
+
+// Errors describe why the program doesn't have that shape
+pub fn loop_shape(program: &[u8]) -> Result<LoopShape, SolveError> {
+    let Loop { outputs, registers } = run_symbolically(program)?;
+    let [a, _, _] = &registers;
+    let shift = match a {
+        Expr::Binary(left, ">>", right) if **left == Expr::Register('a') => match **right {
+            Expr::Literal(shift @ 1..=63) => Some(shift as u32),
+            _ => None,
+        },
+        _ => None,
+    }
+    .ok_or_else(|| SolveError::new("the loop does not shift a by a constant amount"))?;
+    if outputs.is_empty() {
+        return Err(SolveError::new("the loop does not output anything"));
+    }
+    if let Some(register) = ['b', 'c']
+        .into_iter()
+        .find(|register| outputs.iter().any(|output| output.reads(*register)))
+    {
+        return Err(SolveError::new(format!(
+            "the outputs depend on register {} from the previous iteration",
+            register.to_ascii_uppercase()
+        )));
+    }
+    Ok(LoopShape {
+        shift,
+        outputs: outputs.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disassembles_pairs() {
+        let listing: Vec<String> = disassemble(&[0, 3, 5, 4, 3, 0, 5])
+            .iter()
+            .map(Instruction::to_string)
+            .collect();
+        assert_eq!(
+            listing,
+            [
+                "  0: adv 3         a = a >> 3",
+                "  2: out a         output a % 8",
+                "  4: jnz 0         if a != 0 goto 0",
+            ]
+        );
+    }
+
+    #[test]
+    fn decompiles_a_loop() {
+        assert_eq!(
+            decompile(&[2, 4, 1, 1, 7, 5, 0, 3, 4, 3, 1, 6, 5, 5, 3, 0]).unwrap(),
+            [
+                "do {",
+                "    output ((((a % 8) ^ 1) ^ (a >> ((a % 8) ^ 1))) ^ 6) % 8",
+                "    a = a >> 3",
+                "} while a != 0",
+            ]
+        );
+    }
+
+    #[test]
+    fn loop_shapes_with_other_shifts() {
+        assert_eq!(
+            loop_shape(&[5, 4, 0, 1, 3, 0]).unwrap(),
+            LoopShape {
+                shift: 1,
+                outputs: 1
+            }
+        );
+        // Constant shifts add up, and outputs are counted per iteration
+        assert_eq!(
+            loop_shape(&[0, 2, 5, 4, 0, 3, 5, 4, 5, 4, 3, 0]).unwrap(),
+            LoopShape {
+                shift: 5,
+                outputs: 3
+            }
+        );
+    }
+
+    #[test]
+    fn loop_shape_errors() {
+        let message = |program: &[u8]| loop_shape(program).unwrap_err().message;
+        assert_eq!(
+            message(&[5, 4, 0, 3]),
+            "the program is not a single loop ending in 'jnz 0'"
+        );
+        assert_eq!(
+            message(&[5, 4, 0, 5, 3, 0]),
+            "the loop does not shift a by a constant amount"
+        );
+        assert_eq!(message(&[0, 3, 3, 0]), "the loop does not output anything");
+        assert_eq!(
+            message(&[5, 6, 0, 3, 3, 0]),
+            "the outputs depend on register C from the previous iteration"
+        );
+    }
+}
@@ -0,0 +1,279 @@
+use std::{
+    fmt::Display,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+// Positions are (x, y) with y growing downwards, so north is towards y = 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vector {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const fn new(x: usize, y: usize) -> Point {
+        Point { x, y }
+    }
+
+    pub fn checked_add(self, vector: Vector) -> Option<Point> {
+        Some(Point {
+            x: self.x.checked_add_signed(vector.x)?,
+            y: self.y.checked_add_signed(vector.y)?,
+        })
+    }
+
+    pub fn step(self, direction: Direction) -> Option<Point> {
+        self.checked_add(direction.vector())
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+// Panics when the point would leave the first quadrant, use `checked_add` where that can happen
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, vector: Vector) -> Point {
+        self.checked_add(vector)
+            .unwrap_or_else(|| panic!("{self:?} + {vector:?} has a negative coordinate"))
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Vector {
+            x: self.x as isize - other.x as isize,
+            y: self.y as isize - other.y as isize,
+        }
+    }
+}
+
+impl Vector {
+    // Every neighbour of a point, including diagonals, row by row
+    pub const NEIGHBOURS8: [Vector; 8] = [
+        Vector::new(-1, -1),
+        Vector::new(0, -1),
+        Vector::new(1, -1),
+        Vector::new(-1, 0),
+        Vector::new(1, 0),
+        Vector::new(-1, 1),
+        Vector::new(0, 1),
+        Vector::new(1, 1),
+    ];
+
+    pub const fn new(x: isize, y: isize) -> Vector {
+        Vector { x, y }
+    }
+
+    pub fn manhattan(self) -> usize {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Vector;
+
+    fn mul(self, factor: isize) -> Vector {
+        Vector::new(self.x * factor, self.y * factor)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    // Clockwise from north, so `index` and `from_index` agree with this order
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction::North => Vector::new(0, -1),
+            Direction::East => Vector::new(1, 0),
+            Direction::South => Vector::new(0, 1),
+            Direction::West => Vector::new(-1, 0),
+        }
+    }
+
+    pub fn from_vector(vector: Vector) -> Option<Direction> {
+        Direction::ALL
+            .into_iter()
+            .find(|direction| direction.vector() == vector)
+    }
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn from_index(index: usize) -> Direction {
+        Direction::ALL[index % 4]
+    }
+
+    pub fn rotate_cw(self) -> Direction {
+        Direction::from_index(self.index() + 1)
+    }
+
+    pub fn rotate_ccw(self) -> Direction {
+        Direction::from_index(self.index() + 3)
+    }
+
+    pub fn reverse(self) -> Direction {
+        Direction::from_index(self.index() + 2)
+    }
+
+    // Arrows as drawn in the puzzles, e.g. the guard on day 6 or the moves on day 15
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c {
+            '^' => Some(Direction::North),
+            '>' => Some(Direction::East),
+            'v' => Some(Direction::South),
+            '<' => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    pub fn to_char(self) -> char {
+        match self {
+            Direction::North => '^',
+            Direction::East => '>',
+            Direction::South => 'v',
+            Direction::West => '<',
+        }
+    }
+}
+
+impl From<Direction> for Vector {
+    fn from(direction: Direction) -> Vector {
+        direction.vector()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotations_and_reverse() {
+        use Direction::*;
+        assert_eq!(
+            Direction::ALL.map(Direction::rotate_cw),
+            [East, South, West, North]
+        );
+        for direction in Direction::ALL {
+            assert_eq!(direction.rotate_cw().rotate_ccw(), direction);
+            assert_eq!(direction.rotate_cw().rotate_cw(), direction.reverse());
+            assert_eq!(direction.reverse().vector(), -direction.vector());
+        }
+        assert_eq!(North.rotate_ccw(), West);
+        assert_eq!(West.rotate_cw(), North);
+        assert_eq!(East.reverse(), West);
+    }
+
+    #[test]
+    fn from_index_wraps_around() {
+        for (i, direction) in Direction::ALL.into_iter().enumerate() {
+            assert_eq!(direction.index(), i);
+            assert_eq!(Direction::from_index(i), direction);
+            assert_eq!(Direction::from_index(i + 4), direction);
+        }
+        assert_eq!(Direction::from_index(7), Direction::West);
+    }
+
+    #[test]
+    fn arrows_round_trip() {
+        for direction in Direction::ALL {
+            assert_eq!(Direction::from_char(direction.to_char()), Some(direction));
+        }
+        assert_eq!(Direction::from_char('v'), Some(Direction::South));
+        assert_eq!(Direction::from_char('V'), None);
+        assert_eq!(Direction::from_char('.'), None);
+    }
+
+    #[test]
+    fn from_vector_only_takes_unit_steps() {
+        for direction in Direction::ALL {
+            assert_eq!(Direction::from_vector(direction.into()), Some(direction));
+        }
+        assert_eq!(
+            Direction::from_vector(Vector::new(0, -1)),
+            Some(Direction::North)
+        );
+        assert_eq!(Direction::from_vector(Vector::new(0, -2)), None);
+        assert_eq!(Direction::from_vector(Vector::new(1, 1)), None);
+        assert_eq!(Direction::from_vector(Vector::new(0, 0)), None);
+    }
+
+    #[test]
+    fn checked_add_stops_at_zero() {
+        let origin = Point::new(0, 0);
+        assert_eq!(origin.checked_add(Vector::new(-1, 0)), None);
+        assert_eq!(origin.checked_add(Vector::new(0, -1)), None);
+        assert_eq!(origin.step(Direction::North), None);
+        assert_eq!(
+            Point::new(2, 3).checked_add(Vector::new(-2, -3)),
+            Some(origin)
+        );
+        assert_eq!(Point::new(2, 3) + Vector::new(1, -1) * 2, Point::new(4, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "has a negative coordinate")]
+    fn adding_past_zero_panics() {
+        let _ = Point::new(0, 5) + Vector::new(-1, 0);
+    }
+
+    #[test]
+    fn manhattan_distances() {
+        let a = Point::new(1, 7);
+        let b = Point::new(4, 3);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(b.manhattan(a), 7);
+        assert_eq!(a.manhattan(a), 0);
+        assert_eq!((a - b).manhattan(), 7);
+        assert_eq!(Vector::new(-3, 4).manhattan(), 7);
+        assert!(Vector::NEIGHBOURS8
+            .iter()
+            .all(|vector| (1..=2).contains(&vector.manhattan())));
+    }
+}
@@ -0,0 +1,344 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    geometry::{Direction, Point, Vector},
+    SolveError,
+};
+
+// Cells are stored row by row and addressed by `Point`, with y growing downwards
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl Grid<char> {
+    pub fn parse(input: &str) -> Result<Grid<char>, SolveError> {
+        Grid::parse_with(input, |_, c| Ok(c))
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    // `cell` gets the character together with its slice of input, for errors pointing at it
+    pub fn parse_with(
+        input: &str,
+        mut cell: impl FnMut(&str, char) -> Result<T, SolveError>,
+    ) -> Result<Grid<T>, SolveError> {
+        let mut cells: Vec<T> = Vec::new();
+        let mut width: usize = 0;
+        let mut height: usize = 0;
+        for line in input.lines() {
+            let row_width = line.chars().count();
+            if height == 0 {
+                width = row_width;
+            } else if row_width != width {
+                return Err(SolveError::at_token(
+                    input,
+                    line,
+                    format!("expected {width} cells in row"),
+                ));
+            }
+            for (i, c) in line.char_indices() {
+                cells.push(cell(&line[i..i + c.len_utf8()], c)?);
+            }
+            height += 1;
+        }
+        if width == 0 {
+            return Err(SolveError::new("map is empty"));
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Result<Grid<T>, SolveError> {
+        let mut cells: Vec<T> = Vec::new();
+        let mut width: usize = 0;
+        let mut height: usize = 0;
+        for row in rows {
+            if height == 0 {
+                width = row.len();
+            } else if row.len() != width {
+                return Err(SolveError::new(format!(
+                    "expected {width} cells in row {}",
+                    height + 1
+                )));
+            }
+            cells.extend(row);
+            height += 1;
+        }
+        if width == 0 {
+            return Err(SolveError::new("map is empty"));
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Point) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.y * self.width + pos.x])
+    }
+
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.y * self.width + pos.x])
+    }
+
+    // The point `vector` away from `pos`, if it is still inside the grid
+    pub fn offset(&self, pos: Point, vector: Vector) -> Option<Point> {
+        pos.checked_add(vector).filter(|next| self.contains(*next))
+    }
+
+    pub fn step(&self, pos: Point, direction: Direction) -> Option<Point> {
+        self.offset(pos, direction.vector())
+    }
+
+    pub fn neighbors4(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .iter()
+            .filter_map(move |direction| self.step(pos, *direction))
+    }
+
+    pub fn neighbors8(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        Vector::NEIGHBOURS8
+            .iter()
+            .filter_map(move |vector| self.offset(pos, *vector))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point) -> &T {
+        assert!(self.contains(pos), "{pos} is outside the grid");
+        &self.cells[pos.y * self.width + pos.x]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, pos: Point) -> &mut T {
+        assert!(self.contains(pos), "{pos} is outside the grid");
+        &mut self.cells[pos.y * self.width + pos.x]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 3 wide and 2 high, with 'x' twice so `find_all` has an order to keep
+    fn sample() -> Grid<char> {
+        Grid::parse("axc\ndex\n").unwrap()
+    }
+
+    #[test]
+    fn ragged_rows_are_rejected() {
+        let err = Grid::parse("abc\nab\nabc").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: expected 3 cells in row");
+
+        let err = Grid::from_rows([vec![1, 2, 3], vec![4, 5, 6], vec![7]]).unwrap_err();
+        assert_eq!(err.to_string(), "input: expected 3 cells in row 3");
+    }
+
+    #[test]
+    fn cell_errors_point_at_the_cell() {
+        let input = "12\n3x";
+        let err = Grid::parse_with(input, |token, c| {
+            c.to_digit(10)
+                .ok_or_else(|| SolveError::at_token(input, token, "expected a digit"))
+        })
+        .unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 2: expected a digit");
+    }
+
+    #[test]
+    fn empty_maps_are_rejected() {
+        assert_eq!(Grid::parse("").unwrap_err().message, "map is empty");
+        assert_eq!(Grid::parse("\n").unwrap_err().message, "map is empty");
+        let rows: [Vec<u8>; 0] = [];
+        assert_eq!(Grid::from_rows(rows).unwrap_err().message, "map is empty");
+        assert_eq!(
+            Grid::<u8>::from_rows([vec![]]).unwrap_err().message,
+            "map is empty"
+        );
+    }
+
+    #[test]
+    fn offsets_stay_inside_the_grid() {
+        let grid = sample();
+        let corner = Point::new(2, 1);
+        assert_eq!(
+            grid.offset(corner, Vector::new(-2, -1)),
+            Some(Point::new(0, 0))
+        );
+        assert_eq!(grid.offset(corner, Vector::new(1, 0)), None);
+        assert_eq!(grid.offset(corner, Vector::new(0, 1)), None);
+        assert_eq!(grid.offset(Point::new(0, 0), Vector::new(-1, 0)), None);
+        assert_eq!(grid.step(Point::new(0, 0), Direction::North), None);
+        assert_eq!(
+            grid.step(Point::new(0, 0), Direction::South),
+            Some(Point::new(0, 1))
+        );
+    }
+
+    #[test]
+    fn neighbours_at_corners_and_edges() {
+        let grid = Grid::new(3, 3, '.');
+        let neighbors4 = |x, y| grid.neighbors4(Point::new(x, y)).collect::<Vec<_>>();
+        let neighbors8 = |x, y| grid.neighbors8(Point::new(x, y)).count();
+
+        assert_eq!(neighbors4(0, 0), [Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(neighbors4(2, 2), [Point::new(2, 1), Point::new(1, 2)]);
+        assert_eq!(
+            neighbors4(1, 0),
+            [Point::new(2, 0), Point::new(1, 1), Point::new(0, 0)]
+        );
+        assert_eq!(
+            neighbors4(1, 1),
+            [
+                Point::new(1, 0),
+                Point::new(2, 1),
+                Point::new(1, 2),
+                Point::new(0, 1)
+            ]
+        );
+
+        assert_eq!(neighbors8(0, 0), 3);
+        assert_eq!(neighbors8(2, 0), 3);
+        assert_eq!(neighbors8(0, 1), 5);
+        assert_eq!(neighbors8(1, 1), 8);
+        assert_eq!(
+            grid.neighbors8(Point::new(0, 2)).collect::<Vec<_>>(),
+            [Point::new(0, 1), Point::new(1, 1), Point::new(1, 2)]
+        );
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = sample();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.row(1), ['d', 'e', 'x']);
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            [['a', 'x', 'c'], ['d', 'e', 'x']]
+        );
+        assert_eq!(grid.column(0).collect::<String>(), "ad");
+        assert_eq!(grid.column(2).collect::<String>(), "cx");
+    }
+
+    #[test]
+    fn find_all_goes_row_by_row() {
+        let grid = sample();
+        assert_eq!(
+            grid.find_all(&'x').collect::<Vec<_>>(),
+            [Point::new(1, 0), Point::new(2, 1)]
+        );
+        assert_eq!(grid.find(&'x'), Some(Point::new(1, 0)));
+        assert_eq!(grid.find(&'z'), None);
+    }
+
+    #[test]
+    fn display_gives_back_the_input() {
+        assert_eq!(sample().to_string(), "axc\ndex");
+        let digits = Grid::from_rows([vec![1, 2], vec![3, 4]]).unwrap();
+        assert_eq!(digits.map(|n| n * 2).to_string(), "24\n68");
+    }
+
+    #[test]
+    #[should_panic(expected = "3,0 is outside the grid")]
+    fn indexing_outside_panics() {
+        let _ = sample()[Point::new(3, 0)];
+    }
+}
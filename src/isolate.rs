@@ -0,0 +1,108 @@
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    sync::mpsc,
+    thread,
+    time::Duration,
+};
+
+use crate::pool;
+
+pub enum Outcome<T> {
+    Finished(T),
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+impl<T> Outcome<T> {
+    // How a run that did not finish is reported, e.g. "panicked: Reserved"
+    pub fn failure(&self) -> Option<String> {
+        match self {
+            Outcome::Finished(_) => None,
+            Outcome::Panicked(message) => Some(format!("panicked: {message}")),
+            Outcome::TimedOut(timeout) => Some(format!("timed out after {timeout:?}")),
+        }
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("unknown panic")
+    }
+}
+
+// Threads can't be killed, so a run that times out is left to finish in the background and its
+// result is dropped. Without a timeout `f` runs on the calling thread
+pub fn run<T, F>(timeout: Option<Duration>, f: F) -> Outcome<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let Some(timeout) = timeout else {
+        return match panic::catch_unwind(AssertUnwindSafe(f)) {
+            Ok(value) => Outcome::Finished(value),
+            Err(payload) => Outcome::Panicked(panic_message(payload.as_ref())),
+        };
+    };
+    let (sender, receiver) = mpsc::channel();
+    thread::Builder::new()
+        .stack_size(pool::STACK_SIZE)
+        .spawn(move || {
+            let outcome = match panic::catch_unwind(AssertUnwindSafe(f)) {
+                Ok(value) => Outcome::Finished(value),
+                Err(payload) => Outcome::Panicked(panic_message(payload.as_ref())),
+            };
+            // The runner has stopped waiting if this fails
+            let _ = sender.send(outcome);
+        })
+        .expect("failed to spawn a solver thread");
+    receiver
+        .recv_timeout(timeout)
+        .unwrap_or(Outcome::TimedOut(timeout))
+}
+
+// Panics are reported as results, so the default hook printing them to stderr is swapped out
+// while `f` runs
+pub fn quietly<R>(f: impl FnOnce() -> R) -> R {
+    panic::set_hook(Box::new(|_| {}));
+    let result = f();
+    let _ = panic::take_hook();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hint::black_box;
+
+    use super::*;
+
+    #[test]
+    fn timed_runs_get_a_large_stack() {
+        let outcome = run(Some(Duration::from_secs(30)), || {
+            let buffer = black_box([1u8; 4 * 1024 * 1024]);
+            buffer.iter().map(|&byte| byte as usize).sum::<usize>()
+        });
+        assert!(matches!(outcome, Outcome::Finished(sum) if sum == 4 * 1024 * 1024));
+    }
+
+    #[test]
+    fn panics_are_caught_with_their_message() {
+        let outcome: Outcome<()> = quietly(|| run(None, || panic!("Reserved")));
+        assert_eq!(outcome.failure().as_deref(), Some("panicked: Reserved"));
+        let outcome: Outcome<()> =
+            quietly(|| run(Some(Duration::from_secs(30)), || panic!("Reserved")));
+        assert_eq!(outcome.failure().as_deref(), Some("panicked: Reserved"));
+    }
+
+    #[test]
+    fn slow_runs_time_out() {
+        let outcome = run(Some(Duration::from_millis(10)), || {
+            thread::sleep(Duration::from_secs(1))
+        });
+        assert!(matches!(outcome, Outcome::TimedOut(_)));
+    }
+}
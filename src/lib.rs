@@ -0,0 +1,106 @@
+use std::{fmt::Display, str::FromStr};
+
+pub mod diagnostics;
+pub mod disassemble;
+pub mod geometry;
+pub mod grid;
+pub mod isolate;
+pub mod netlist;
+pub mod output;
+pub mod params;
+pub mod parse;
+pub mod pathfinding;
+pub mod pool;
+pub mod preprocess;
+pub mod solvers;
+pub mod visualize;
+pub mod vm;
+
+pub use params::{Param, Params};
+
+#[derive(Debug)]
+pub struct SolveError {
+    pub day: Option<u8>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> SolveError {
+        SolveError {
+            day: None,
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    // token must be a slice of input, otherwise no location is recorded
+    pub fn at_token(input: &str, token: &str, message: impl Into<String>) -> SolveError {
+        let mut error = SolveError::new(message);
+        let offset = (token.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        if let Some(before) = input.get(..offset) {
+            error.line = Some(before.matches('\n').count() + 1);
+            error.column = Some(before.chars().rev().take_while(|c| *c != '\n').count() + 1);
+        }
+        error
+    }
+
+    pub fn with_day(mut self, day: u8) -> SolveError {
+        self.day = Some(day);
+        self
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "Day {day}, ")?;
+        }
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {line}, column {column}: ")?,
+            (Some(line), None) => write!(f, "line {line}: ")?,
+            _ => write!(f, "input: ")?,
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+pub fn parse_token<T>(input: &str, token: &str) -> Result<T, SolveError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    token.parse().map_err(|err| {
+        SolveError::at_token(input, token, format!("could not parse '{token}': {err}"))
+    })
+}
+
+// Solvers are shared between threads, so any state built while solving must stay inside a call
+pub trait Solver: Send + Sync {
+    fn params(&self) -> &'static [Param] {
+        &[]
+    }
+    // The runner normalizes line endings and trailing whitespace first unless this returns true
+    fn raw_input(&self) -> bool {
+        false
+    }
+    fn solve_part1(&self, input: &str, params: &Params) -> Result<String, SolveError>;
+    fn solve_part2(&self, input: &str, params: &Params) -> Result<String, SolveError>;
+}
+
+pub fn solve_part(
+    solver: &dyn Solver,
+    part: u8,
+    input: &str,
+    params: &Params,
+) -> Result<String, SolveError> {
+    if part == 1 {
+        solver.solve_part1(input, params)
+    } else {
+        solver.solve_part2(input, params)
+    }
+}
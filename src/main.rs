@@ -1,81 +1,268 @@
 use std::{
     cmp::max,
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     fmt::Display,
-    fs::{self, read_to_string},
-    io,
-    num::ParseIntError,
-    path::PathBuf,
+    fs::{self, read_to_string, File},
+    io::{self, BufWriter},
+    num::{NonZeroUsize, ParseIntError},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::Arc,
+    time::{Duration, Instant},
 };
 
-use clap::Parser;
+use advent_of_code_2024::{
+    isolate::{self, Outcome},
+    output::{self, OutputFormat, Status, SummaryRow},
+    params, pool, preprocess, solve_part,
+    solvers::{self, Registration},
+    visualize::{self, FrameLog, FrameSink, ImageFormat, Images, Terminal},
+    Param, Params, SolveError, Solver,
+};
+use bench::BenchFormat;
+use clap::{Parser, Subcommand, ValueEnum};
+use debugger::TraceFormat;
+
+mod bench;
+mod debugger;
 
-mod solvers;
+const DEFAULT_INPUT: &str = "default";
 
 struct DayInputPair {
-    input: String,
+    input: Arc<str>,
+    year: u16,
     day: u8,
+    name: String,
+    expected: [Option<String>; 2],
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum PartSelection {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    Both,
+}
+
+impl PartSelection {
+    fn includes(&self, part: u8) -> bool {
+        match self {
+            PartSelection::One => part == 1,
+            PartSelection::Two => part == 2,
+            PartSelection::Both => true,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Visualize {
+    Terminal,
+    Ppm,
+    Pgm,
+    Log,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Prints every registered solver
+    List,
+    /// Prints the listing and decompiled loop of a day 17 program
+    Disassemble {
+        /// The day 17 input, `-` reads it from stdin
+        path: PathBuf,
+    },
+    /// Writes every instruction a day 17 program executes with the registers before and after it
+    Trace {
+        /// The day 17 input, `-` reads it from stdin
+        path: PathBuf,
+        /// Replaces the value register A starts with
+        #[arg(long)]
+        register_a: Option<usize>,
+        /// Format of the trace
+        #[arg(long, value_enum, default_value_t = TraceFormat::Text)]
+        format: TraceFormat,
+        /// Writes the trace to a file instead of stdout
+        #[arg(long, value_name = "FILE")]
+        trace_output: Option<PathBuf>,
+        /// Stops the trace after this many instructions
+        #[arg(long, default_value_t = 10_000)]
+        max_steps: usize,
+    },
+    /// Steps through a day 17 program, reading commands from stdin
+    Debug {
+        /// The day 17 input, which can't be `-` as stdin holds the commands
+        path: PathBuf,
+        /// Replaces the value register A starts with
+        #[arg(long)]
+        register_a: Option<usize>,
+        /// Instruction pointer to stop at, can be repeated
+        #[arg(long = "break", value_name = "IP")]
+        breakpoints: Vec<usize>,
+        /// Most instructions run by a single `continue`
+        #[arg(long, default_value_t = 10_000)]
+        max_steps: usize,
+    },
 }
 
+/// Runs Advent of Code solvers on the inputs in the test directory
 #[derive(Parser)]
 struct CliArgs {
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// Defaults to the latest year with registered solvers
     #[arg(short, long)]
+    year: Option<u16>,
+    /// Solves a single day
+    #[arg(short, long, conflicts_with = "all")]
     day: Option<u8>,
+    /// Directory holding a subdirectory of inputs per year [default: ./tests]
     #[arg(short, long)]
     test_dir: Option<PathBuf>,
+    /// Solves only the input with this name, e.g. `example1` for `6.example1`
+    #[arg(short, long)]
+    input: Option<String>,
+    /// Reads the input of `--day` from a file instead of the test directory, `-` reads stdin
+    #[arg(long, requires = "day", conflicts_with_all = ["input", "all"])]
+    input_file: Option<PathBuf>,
+    /// Part of each day to solve
+    #[arg(short, long, value_enum, default_value_t = PartSelection::Both)]
+    part: PartSelection,
+    /// Exits with a failure when an answer differs from its `.expected` file
+    #[arg(long)]
+    verify: bool,
+    /// Solves every day with an input and prints a summary table
+    #[arg(short, long)]
+    all: bool,
+    /// Number of solvers run at once by `--all`
+    #[arg(
+        short,
+        long,
+        default_value = "1",
+        requires = "all",
+        conflicts_with_all = ["day", "bench"]
+    )]
+    jobs: NonZeroUsize,
+    /// Writes a record per part instead of text, to stdout with diagnostics going to stderr
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Text, conflicts_with = "bench")]
+    output: OutputFormat,
+    /// Reports solvers running longer as timed out, without stopping the rest
+    #[arg(long, value_name = "SECONDS", value_parser = clap::value_parser!(u64).range(1..))]
+    timeout: Option<u64>,
+    /// Shows the simulation of days 6, 14, 15 and 16 frame by frame
+    #[arg(long, value_enum, conflicts_with_all = ["all", "bench"])]
+    visualize: Option<Visualize>,
+    /// A directory for images [default: frames], or a file for the frame log instead of stderr
+    #[arg(long, value_name = "PATH", requires = "visualize")]
+    visualize_output: Option<PathBuf>,
+    /// Delay between frames drawn in the terminal
+    #[arg(long, value_name = "MS", default_value_t = 50, requires = "visualize")]
+    frame_delay: u64,
+    /// Reports inputs that had their line endings or trailing whitespace normalized
+    #[arg(long)]
+    warn_input: bool,
+    /// Times the selected day, or every day with `--all`
+    #[arg(long)]
+    bench: bool,
+    /// Timed runs of each part
+    #[arg(long, default_value_t = 10, requires = "bench")]
+    iterations: usize,
+    /// Untimed runs of each part before the timed ones
+    #[arg(long, default_value_t = 1, requires = "bench")]
+    warmup: usize,
+    /// Also writes the benchmark results in nanoseconds to a file
+    #[arg(long, requires = "bench")]
+    bench_output: Option<PathBuf>,
+    /// Format of the `--bench-output` file
+    #[arg(long, value_enum, default_value_t = BenchFormat::Json, requires = "bench_output")]
+    bench_format: BenchFormat,
+    /// Overrides a solver parameter, can be repeated
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = params::parse_override)]
+    params: Vec<(String, u64)>,
+    /// Prints the parameters of a day with their defaults
+    #[arg(long, value_name = "DAY", conflicts_with_all = ["day", "all", "bench"])]
+    list_params: Option<u8>,
+}
+
+impl CliArgs {
+    fn year(&self) -> u16 {
+        self.year
+            .or_else(solvers::latest_year)
+            .expect("no solvers are registered")
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.timeout.map(Duration::from_secs)
+    }
 }
 
 #[derive(Debug)]
 enum ParseDayInputPairError {
-    IoError(io::Error),
-    DayParseError(ParseIntError),
-    OtherError(String),
+    Io(io::Error),
+    DayParse(ParseIntError),
+    Other(String),
 }
 
 impl Display for ParseDayInputPairError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            ParseDayInputPairError::IoError(error) => writeln!(f, "IoError: {error}"),
-            ParseDayInputPairError::DayParseError(parse_int_error) => {
+            ParseDayInputPairError::Io(error) => writeln!(f, "IoError: {error}"),
+            ParseDayInputPairError::DayParse(parse_int_error) => {
                 writeln!(f, "DayParseError: {parse_int_error}")
             }
-            ParseDayInputPairError::OtherError(error_msg) => writeln!(f, "OtherError: {error_msg}"),
+            ParseDayInputPairError::Other(error_msg) => writeln!(f, "OtherError: {error_msg}"),
         }
     }
 }
 
 impl From<io::Error> for ParseDayInputPairError {
     fn from(value: io::Error) -> Self {
-        Self::IoError(value)
+        Self::Io(value)
     }
 }
 
 impl From<ParseIntError> for ParseDayInputPairError {
     fn from(value: ParseIntError) -> Self {
-        Self::DayParseError(value)
+        Self::DayParse(value)
     }
 }
 
 impl From<&str> for ParseDayInputPairError {
     fn from(s: &str) -> Self {
-        Self::OtherError(s.to_string())
+        Self::Other(s.to_string())
     }
 }
 
 impl From<String> for ParseDayInputPairError {
     fn from(s: String) -> Self {
-        Self::OtherError(s)
+        Self::Other(s)
     }
 }
 
-pub trait Solver {
-    fn solve(&self, input: &String) -> String;
+type TestFiles = HashMap<u8, BTreeMap<String, PathBuf>>;
+
+// `N` and `N.txt` are the default input, anything else is named by what follows `N.`
+fn input_name(rest: &str) -> String {
+    match rest.strip_suffix(".txt").unwrap_or(rest) {
+        "" | "txt" => String::from(DEFAULT_INPUT),
+        name => name.to_string(),
+    }
 }
 
-fn parse_args(args: &CliArgs) -> Result<DayInputPair, ParseDayInputPairError> {
-    let paths = fs::read_dir(args.test_dir.clone().unwrap_or("./tests".into()))?;
-    let mut max_day: u8 = 0;
-    let mut day_dir_map: HashMap<u8, PathBuf> = HashMap::new();
+// Tests live in a subdirectory named after the year, falling back to a flat test directory
+fn test_dir(args: &CliArgs) -> PathBuf {
+    let test_dir = args.test_dir.clone().unwrap_or("./tests".into());
+    let year_dir = test_dir.join(args.year().to_string());
+    if year_dir.is_dir() {
+        year_dir
+    } else {
+        test_dir
+    }
+}
+
+fn find_test_files(args: &CliArgs) -> Result<TestFiles, ParseDayInputPairError> {
+    let paths = fs::read_dir(test_dir(args))?;
+    let mut day_dir_map: TestFiles = HashMap::new();
 
     for maybe_path in paths {
         let path = maybe_path?;
@@ -84,38 +271,605 @@ fn parse_args(args: &CliArgs) -> Result<DayInputPair, ParseDayInputPairError> {
         let path_str = path_os_string
             .to_str()
             .ok_or("Failed to convert OS String to str")?;
+        if path_str.ends_with(".expected") {
+            continue;
+        }
         let file_extension_dot_index = path_str.find('.').unwrap_or(path_str.len());
         if let Ok(day) = path_str[0..file_extension_dot_index].parse::<u8>() {
             if child_type.is_file() {
-                day_dir_map.insert(day, path.path());
-                max_day = max(max_day, day);
+                let rest = path_str[file_extension_dot_index..].trim_start_matches('.');
+                let name = input_name(rest);
+                let inputs = day_dir_map.entry(day).or_default();
+                if let Some(other) = inputs.insert(name.clone(), path.path()) {
+                    return Err(format!(
+                        "Both {} and {} are input '{name}' for day {day}",
+                        other.display(),
+                        path.path().display()
+                    )
+                    .into());
+                }
             }
         }
     }
 
+    Ok(day_dir_map)
+}
+
+// Solvers get normalized input unless they ask for it untouched through `Solver::raw_input`
+fn prepare_input(year: u16, day: u8, name: &str, raw: String, warn: bool) -> Arc<str> {
+    if solvers::get(year, day).is_some_and(|registration| (registration.solver)().raw_input()) {
+        return raw.into();
+    }
+    let normalized = preprocess::normalize(&raw);
+    if warn && !normalized.changes.is_empty() {
+        let changes: Vec<String> = normalized.changes.iter().map(ToString::to_string).collect();
+        eprintln!("Warning: day {day} input {name}: {}", changes.join(", "));
+    }
+    normalized.text.into()
+}
+
+fn load_day(
+    year: u16,
+    day: u8,
+    name: &str,
+    input_path: &Path,
+    warn: bool,
+) -> Result<DayInputPair, ParseDayInputPairError> {
+    let prefix = if name == DEFAULT_INPUT {
+        day.to_string()
+    } else {
+        format!("{day}.{name}")
+    };
+    let mut expected: [Option<String>; 2] = [None, None];
+    for (i, answer) in expected.iter_mut().enumerate() {
+        let expected_path = input_path.with_file_name(format!("{prefix}.part{}.expected", i + 1));
+        if expected_path.is_file() {
+            *answer = Some(read_to_string(expected_path)?);
+        }
+    }
+
+    Ok(DayInputPair {
+        input: prepare_input(year, day, name, read_to_string(input_path)?, warn),
+        year,
+        day,
+        name: name.to_string(),
+        expected,
+    })
+}
+
+// Loads every input of a day, or only the one picked with `--input`
+fn load_inputs(
+    args: &CliArgs,
+    day: u8,
+    inputs: &BTreeMap<String, PathBuf>,
+) -> Result<Vec<DayInputPair>, ParseDayInputPairError> {
+    inputs
+        .iter()
+        .filter(|(name, _)| args.input.as_ref().is_none_or(|input| input == *name))
+        .map(|(name, input_path)| load_day(args.year(), day, name, input_path, args.warn_input))
+        .collect()
+}
+
+fn read_input_file(
+    year: u16,
+    day: u8,
+    path: &Path,
+    warn: bool,
+) -> Result<DayInputPair, ParseDayInputPairError> {
+    let (input, name) = if path == Path::new("-") {
+        (io::read_to_string(io::stdin())?, String::from("stdin"))
+    } else {
+        (read_to_string(path)?, path.display().to_string())
+    };
+    Ok(DayInputPair {
+        input: prepare_input(year, day, &name, input, warn),
+        year,
+        day,
+        name,
+        expected: [None, None],
+    })
+}
+
+fn parse_args(args: &CliArgs) -> Result<Vec<DayInputPair>, ParseDayInputPairError> {
+    if let (Some(path), Some(day)) = (&args.input_file, args.day) {
+        return Ok(vec![read_input_file(
+            args.year(),
+            day,
+            path,
+            args.warn_input,
+        )?]);
+    }
+
+    let day_dir_map = find_test_files(args)?;
+    let max_day = day_dir_map.keys().copied().max().unwrap_or(0);
+
     let target_day = args.day.unwrap_or(max_day);
-    if !day_dir_map.contains_key(&target_day) {
+    let Some(inputs) = day_dir_map.get(&target_day) else {
         return Err(format!("Could not find test file for day {target_day}").into());
+    };
+
+    let input_pairs = load_inputs(args, target_day, inputs)?;
+    if input_pairs.is_empty() {
+        let names: Vec<&str> = inputs.keys().map(String::as_str).collect();
+        return Err(format!(
+            "Could not find input '{}' for day {target_day}, found: {}",
+            args.input.as_deref().unwrap_or_default(),
+            names.join(", ")
+        )
+        .into());
     }
+    Ok(input_pairs)
+}
 
-    Ok(DayInputPair {
-        input: read_to_string(day_dir_map.get(&target_day).unwrap())?,
-        day: target_day,
+// Prints the lines that differ between the expected and actual answers
+fn print_diff(expected: &str, actual: &str) {
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+    for i in 0..max(expected_lines.len(), actual_lines.len()) {
+        let expected_line = expected_lines.get(i);
+        let actual_line = actual_lines.get(i);
+        if expected_line == actual_line {
+            continue;
+        }
+        if let Some(line) = expected_line {
+            println!("- {line}");
+        }
+        if let Some(line) = actual_line {
+            println!("+ {line}");
+        }
+    }
+}
+
+// Every `--param` must be declared by at least one of the solvers being run
+// `no_params` explains the error when none of the solvers take parameters
+fn check_params<'a>(
+    args: &CliArgs,
+    solvers: impl IntoIterator<Item = &'a dyn Solver>,
+    no_params: &str,
+) -> Result<(), String> {
+    let declared: Vec<&Param> = solvers
+        .into_iter()
+        .flat_map(|solver| solver.params())
+        .collect();
+    for (name, _) in &args.params {
+        if declared.is_empty() {
+            return Err(format!("Unknown parameter '{name}', {no_params}"));
+        }
+        if !declared.iter().any(|param| param.name == name) {
+            let names: Vec<&str> = declared.iter().map(|param| param.name).collect();
+            return Err(format!(
+                "Unknown parameter '{name}', expected one of: {}",
+                names.join(", ")
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn list_params(year: u16, day: u8) -> ExitCode {
+    let Some(solver) = solvers::get_solver(year, day) else {
+        eprintln!("Could not find solver for day {day} of {year}");
+        return ExitCode::FAILURE;
+    };
+    if solver.params().is_empty() {
+        println!("Day {day} has no parameters");
+    }
+    for param in solver.params() {
+        println!("{param}");
+    }
+    ExitCode::SUCCESS
+}
+
+// Solves one part under panic capture and the `--timeout` of `args`
+fn isolated_solve(
+    args: &CliArgs,
+    solver: &Arc<dyn Solver>,
+    input_pair: &DayInputPair,
+    part: u8,
+    params: &Params,
+) -> Outcome<(Result<String, SolveError>, Duration)> {
+    let solver = Arc::clone(solver);
+    let input = Arc::clone(&input_pair.input);
+    let params = params.clone();
+    // The clock runs on the solving thread, leaving out the spawn and the wait for its result
+    isolate::run(args.timeout(), move || {
+        let start = Instant::now();
+        let result = solve_part(solver.as_ref(), part, &input, &params);
+        (result, start.elapsed())
     })
 }
 
-fn main() {
-    let maybe_input_pair = parse_args(&CliArgs::parse());
-    if let Err(parse_err) = maybe_input_pair {
-        println!("Encountered error while parsing input: {}", parse_err);
-        return;
+fn summary_row(
+    args: &CliArgs,
+    solver: &Arc<dyn Solver>,
+    input_pair: &DayInputPair,
+    part: u8,
+    params: &Params,
+) -> SummaryRow {
+    let outcome = isolated_solve(args, solver, input_pair, part, params);
+    // A part that panicked has no time, and one that timed out ran for at least the timeout
+    let (answer, time, status) = match outcome {
+        Outcome::Finished((Ok(answer), elapsed)) => {
+            let status = match &input_pair.expected[part as usize - 1] {
+                Some(expected) if expected.trim_end() != answer.trim_end() => Status::Mismatch,
+                _ => Status::Ok,
+            };
+            (answer, Some(elapsed), status)
+        }
+        Outcome::Finished((Err(solve_err), elapsed)) => {
+            (solve_err.to_string(), Some(elapsed), Status::Error)
+        }
+        Outcome::Panicked(message) => (message, None, Status::Panicked),
+        Outcome::TimedOut(timeout) => (
+            format!("timed out after {timeout:?}"),
+            Some(timeout),
+            Status::TimedOut,
+        ),
+    };
+    SummaryRow {
+        year: input_pair.year,
+        day: input_pair.day,
+        name: input_pair.name.clone(),
+        part,
+        answer,
+        time,
+        status,
+    }
+}
+
+struct DayRun {
+    registration: &'static Registration,
+    solver: Arc<dyn Solver>,
+    params: Params,
+    input_pairs: Vec<DayInputPair>,
+}
+
+enum Task<'a> {
+    Solve(&'a DayRun, &'a DayInputPair, u8),
+    MissingInput(u8, u8),
+}
+
+fn run_all(args: &CliArgs) -> Result<Vec<SummaryRow>, ParseDayInputPairError> {
+    let day_dir_map = find_test_files(args)?;
+    let year = args.year();
+    let mut day_runs: Vec<DayRun> = Vec::new();
+    for registration in solvers::list()
+        .into_iter()
+        .filter(|registration| registration.year == year)
+    {
+        let day = registration.day;
+        let solver: Arc<dyn Solver> = Arc::from((registration.solver)());
+        let input_pairs = match day_dir_map.get(&day) {
+            Some(inputs) => load_inputs(args, day, inputs)?,
+            None => Vec::new(),
+        };
+        let params = Params::new(solver.params(), &args.params);
+        day_runs.push(DayRun {
+            registration,
+            solver,
+            params,
+            input_pairs,
+        });
+    }
+
+    // Tasks are queued in day order and the rows come back in the same order
+    let mut tasks: Vec<Task> = Vec::new();
+    for day_run in &day_runs {
+        let registration = day_run.registration;
+        let parts: Vec<u8> = [1, 2]
+            .into_iter()
+            .filter(|part| args.part.includes(*part) && registration.supports(*part))
+            .collect();
+        if day_run.input_pairs.is_empty() {
+            tasks.extend(
+                parts
+                    .iter()
+                    .map(|&part| Task::MissingInput(registration.day, part)),
+            );
+        }
+        for input_pair in &day_run.input_pairs {
+            tasks.extend(
+                parts
+                    .iter()
+                    .map(|&part| Task::Solve(day_run, input_pair, part)),
+            );
+        }
+    }
+
+    let rows = pool::map(&tasks, args.jobs, |task| match *task {
+        Task::Solve(day_run, input_pair, part) => {
+            summary_row(args, &day_run.solver, input_pair, part, &day_run.params)
+        }
+        Task::MissingInput(day, part) => SummaryRow {
+            year,
+            day,
+            name: args.input.clone().unwrap_or_default(),
+            part,
+            answer: String::new(),
+            time: None,
+            status: Status::MissingInput,
+        },
+    });
+    Ok(rows)
+}
+
+fn print_summary(rows: &[SummaryRow]) {
+    // Multi-line answers are cut down to their first line to keep the table readable
+    let answers: Vec<String> = rows
+        .iter()
+        .map(|row| match row.answer.trim_end().split_once('\n') {
+            Some((first, _)) => format!("{first} ..."),
+            None => row.answer.trim_end().to_string(),
+        })
+        .collect();
+    let times: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            row.time
+                .map(|time| format!("{time:.2?}"))
+                .unwrap_or_default()
+        })
+        .collect();
+    let name_width = rows
+        .iter()
+        .map(|row| row.name.len())
+        .fold("Input".len(), max);
+    let answer_width = answers.iter().map(|a| a.len()).fold("Answer".len(), max);
+    let time_width = times.iter().map(|t| t.len()).fold("Time".len(), max);
+
+    println!(
+        "{:>3}  {:<name_width$}  {:>4}  {:<answer_width$}  {:>time_width$}  Status",
+        "Day", "Input", "Part", "Answer", "Time"
+    );
+    for ((row, answer), time) in rows.iter().zip(answers).zip(times) {
+        println!(
+            "{:>3}  {:<name_width$}  {:>4}  {:<answer_width$}  {:>time_width$}  {}",
+            row.day, row.name, row.part, answer, time, row.status
+        );
+    }
+}
+
+// Prints the rows in the selected output format and decides the exit status
+fn report(args: &CliArgs, rows: &[SummaryRow]) -> ExitCode {
+    let written = match args.output {
+        OutputFormat::Text => {
+            print_summary(rows);
+            Ok(())
+        }
+        OutputFormat::Json => output::write_json(rows, &mut io::stdout().lock()),
+        OutputFormat::Csv => output::write_csv(rows, &mut io::stdout().lock()),
+    };
+    if let Err(io_err) = written {
+        eprintln!("Encountered error while writing results: {io_err}");
+        return ExitCode::FAILURE;
+    }
+    let failed = rows.iter().any(|row| match row.status {
+        Status::Error | Status::Panicked | Status::TimedOut => true,
+        Status::Mismatch => args.verify,
+        Status::Ok | Status::MissingInput => false,
+    });
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn list_solvers() -> ExitCode {
+    let registrations = solvers::list();
+    let title_width = registrations
+        .iter()
+        .map(|registration| registration.title.len())
+        .fold("Title".len(), max);
+    println!(
+        "{:>4}  {:>3}  {:<title_width$}  Parts",
+        "Year", "Day", "Title"
+    );
+    for registration in registrations {
+        let parts: Vec<String> = registration
+            .parts
+            .iter()
+            .map(|part| part.to_string())
+            .collect();
+        println!(
+            "{:>4}  {:>3}  {:<title_width$}  {}",
+            registration.year,
+            registration.day,
+            registration.title,
+            parts.join(", ")
+        );
+    }
+    ExitCode::SUCCESS
+}
+
+fn start_visualizer(args: &CliArgs) -> io::Result<()> {
+    let Some(mode) = args.visualize else {
+        return Ok(());
+    };
+    let sink: Box<dyn FrameSink> = match mode {
+        Visualize::Terminal => Box::new(Terminal::new(
+            io::stderr(),
+            Duration::from_millis(args.frame_delay),
+        )),
+        Visualize::Ppm | Visualize::Pgm => {
+            let format = if mode == Visualize::Ppm {
+                ImageFormat::Ppm
+            } else {
+                ImageFormat::Pgm
+            };
+            let dir = args.visualize_output.clone().unwrap_or("frames".into());
+            Box::new(Images::new(dir, format, 4)?)
+        }
+        Visualize::Log => match &args.visualize_output {
+            Some(path) => Box::new(FrameLog::new(BufWriter::new(File::create(path)?))),
+            None => Box::new(FrameLog::new(io::stderr())),
+        },
+    };
+    visualize::install(sink);
+    Ok(())
+}
+
+fn print_answers(
+    args: &CliArgs,
+    registration: &Registration,
+    solver: &Arc<dyn Solver>,
+    params: &Params,
+    input_pairs: &[DayInputPair],
+) -> ExitCode {
+    let (year, day) = (registration.year, registration.day);
+    println!("Year: {year}");
+    println!("Day: {day}");
+    let mut mismatches = 0;
+    let mut errors = 0;
+    for input_pair in input_pairs {
+        println!("Input: {}", input_pair.name);
+        for part in [1, 2] {
+            if !args.part.includes(part) || !registration.supports(part) {
+                continue;
+            }
+            let outcome = isolated_solve(args, solver, input_pair, part, params);
+            let failure = match outcome {
+                Outcome::Finished((Ok(answer), _)) => {
+                    println!("Part {part}:\n{answer}");
+                    match &input_pair.expected[part as usize - 1] {
+                        Some(expected) if expected.trim_end() == answer.trim_end() => {
+                            println!("PASS")
+                        }
+                        Some(expected) => {
+                            println!("FAIL (- expected, + actual)");
+                            print_diff(expected.trim_end(), answer.trim_end());
+                            mismatches += 1;
+                        }
+                        None if args.verify => println!("No expected answer for part {part}"),
+                        None => (),
+                    }
+                    continue;
+                }
+                Outcome::Finished((Err(solve_err), _)) => solve_err.with_day(day).to_string(),
+                _ => format!("Day {day}, {}", outcome.failure().unwrap_or_default()),
+            };
+            eprintln!(
+                "Encountered error while solving part {part} of input {}: {failure}",
+                input_pair.name
+            );
+            errors += 1;
+        }
+    }
+    if errors > 0 || (args.verify && mismatches > 0) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn main() -> ExitCode {
+    let args = CliArgs::parse();
+    match &args.command {
+        Some(Command::List) => return list_solvers(),
+        Some(Command::Disassemble { path }) => return debugger::print_disassembly(&args, path),
+        Some(Command::Trace {
+            path,
+            register_a,
+            format,
+            trace_output,
+            max_steps,
+        }) => {
+            return debugger::trace(
+                &args,
+                path,
+                *register_a,
+                *format,
+                trace_output.as_deref(),
+                *max_steps,
+            )
+        }
+        Some(Command::Debug {
+            path,
+            register_a,
+            breakpoints,
+            max_steps,
+        }) => return debugger::debug(&args, path, *register_a, breakpoints, *max_steps),
+        None => (),
+    }
+    let year = args.year();
+    if !solvers::years().contains(&year) {
+        eprintln!("No solvers are registered for {year}");
+        return ExitCode::FAILURE;
+    }
+    if let Some(day) = args.list_params {
+        return list_params(year, day);
+    }
+    if args.all {
+        let all_solvers: Vec<Box<dyn Solver>> = solvers::iter()
+            .filter(|registration| registration.year == year)
+            .map(|registration| (registration.solver)())
+            .collect();
+        if let Err(param_err) = check_params(
+            &args,
+            all_solvers.iter().map(|solver| solver.as_ref()),
+            &format!("none of {year}'s solvers take parameters"),
+        ) {
+            eprintln!("{param_err}");
+            return ExitCode::FAILURE;
+        }
+    }
+    if args.bench {
+        return bench::run(&args);
     }
-    let input_pair = maybe_input_pair.unwrap();
-    println!("Day: {}", input_pair.day);
-    let maybe_solver = solvers::get_solver(input_pair.day);
-    if maybe_solver.is_none() {
-        println!("Could not find solver for day {}", input_pair.day);
+    if args.all {
+        let rows = match isolate::quietly(|| run_all(&args)) {
+            Ok(rows) => rows,
+            Err(parse_err) => {
+                eprintln!("Encountered error while parsing input: {}", parse_err);
+                return ExitCode::FAILURE;
+            }
+        };
+        return report(&args, &rows);
+    }
+    let input_pairs = match parse_args(&args) {
+        Ok(input_pairs) => input_pairs,
+        Err(parse_err) => {
+            eprintln!("Encountered error while parsing input: {}", parse_err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let day = input_pairs[0].day;
+    let Some(registration) = solvers::get(year, day) else {
+        eprintln!("Could not find solver for day {day} of {year}");
+        return ExitCode::FAILURE;
+    };
+    let solver: Arc<dyn Solver> = Arc::from((registration.solver)());
+    if let Err(param_err) = check_params(
+        &args,
+        [solver.as_ref()],
+        &format!("day {day} takes no parameters"),
+    ) {
+        eprintln!("{param_err}");
+        return ExitCode::FAILURE;
+    }
+    let params = Params::new(solver.params(), &args.params);
+    if let Err(io_err) = start_visualizer(&args) {
+        eprintln!("Encountered error while starting the visualizer: {io_err}");
+        return ExitCode::FAILURE;
+    }
+    let status = isolate::quietly(|| {
+        if args.output != OutputFormat::Text {
+            let mut rows: Vec<SummaryRow> = Vec::new();
+            for input_pair in &input_pairs {
+                for part in [1, 2] {
+                    if args.part.includes(part) && registration.supports(part) {
+                        rows.push(summary_row(&args, &solver, input_pair, part, &params));
+                    }
+                }
+            }
+            report(&args, &rows)
+        } else {
+            print_answers(&args, registration, &solver, &params, &input_pairs)
+        }
+    });
+    if let Err(io_err) = visualize::finish() {
+        eprintln!("Encountered error while visualizing: {io_err}");
+        return ExitCode::FAILURE;
     }
-    let solver = maybe_solver.unwrap();
-    println!("Result:\n{}", solver.solve(&input_pair.input));
+    status
 }
@@ -0,0 +1,451 @@
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+use crate::SolveError;
+
+// Two-input logic gates, as wired up in 2024 day 24
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GateKind {
+    And,
+    Or,
+    Xor,
+}
+
+impl GateKind {
+    pub fn eval(self, a: bool, b: bool) -> bool {
+        match self {
+            GateKind::And => a && b,
+            GateKind::Or => a || b,
+            GateKind::Xor => a ^ b,
+        }
+    }
+}
+
+impl FromStr for GateKind {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "AND" => Ok(GateKind::And),
+            "OR" => Ok(GateKind::Or),
+            "XOR" => Ok(GateKind::Xor),
+            _ => Err(String::from("expected XOR, OR or AND")),
+        }
+    }
+}
+
+impl Display for GateKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            GateKind::And => "AND",
+            GateKind::Or => "OR",
+            GateKind::Xor => "XOR",
+        })
+    }
+}
+
+// Wires are referred to by their index in the netlist
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gate {
+    pub kind: GateKind,
+    pub inputs: [usize; 2],
+    pub output: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct NetlistBuilder {
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+    gates: Vec<Gate>,
+    drivers: HashMap<usize, usize>,
+}
+
+impl NetlistBuilder {
+    pub fn new() -> NetlistBuilder {
+        NetlistBuilder::default()
+    }
+
+    // The index of the wire called `name`, adding it if it's new
+    pub fn wire(&mut self, name: &str) -> usize {
+        if let Some(&wire) = self.ids.get(name) {
+            return wire;
+        }
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), self.names.len() - 1);
+        self.names.len() - 1
+    }
+
+    // Returns `None` when another gate already drives `output`
+    pub fn gate(&mut self, kind: GateKind, a: &str, b: &str, output: &str) -> Option<usize> {
+        let inputs = [self.wire(a), self.wire(b)];
+        let output = self.wire(output);
+        if self.drivers.contains_key(&output) {
+            return None;
+        }
+        self.gates.push(Gate {
+            kind,
+            inputs,
+            output,
+        });
+        self.drivers.insert(output, self.gates.len() - 1);
+        Some(self.gates.len() - 1)
+    }
+
+    // Sorts the gates so each one comes after the gates driving its inputs, which fails if the
+    // gates form a cycle
+    pub fn build(self) -> Result<Netlist, SolveError> {
+        let mut drivers: Vec<Option<usize>> = vec![None; self.names.len()];
+        for (wire, gate) in self.drivers {
+            drivers[wire] = Some(gate);
+        }
+        let mut readers: Vec<Vec<usize>> = vec![Vec::new(); self.names.len()];
+        for (i, gate) in self.gates.iter().enumerate() {
+            for input in gate.inputs {
+                readers[input].push(i);
+            }
+        }
+        let mut netlist = Netlist {
+            names: self.names,
+            ids: self.ids,
+            gates: self.gates,
+            drivers,
+            readers,
+            order: Vec::new(),
+        };
+        netlist.sort()?;
+        Ok(netlist)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Netlist {
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+    gates: Vec<Gate>,
+    drivers: Vec<Option<usize>>,
+    // The gates reading each wire, which a gate reading the same wire twice is in twice
+    readers: Vec<Vec<usize>>,
+    order: Vec<usize>,
+}
+
+impl Netlist {
+    pub fn wire_count(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn wire(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, wire: usize) -> &str {
+        &self.names[wire]
+    }
+
+    pub fn gates(&self) -> &[Gate] {
+        &self.gates
+    }
+
+    pub fn gate(&self, gate: usize) -> &Gate {
+        &self.gates[gate]
+    }
+
+    // The gate whose output is `wire`, which is `None` for the circuit's inputs
+    pub fn driver(&self, wire: usize) -> Option<usize> {
+        self.drivers[wire]
+    }
+
+    pub fn readers(&self, wire: usize) -> &[usize] {
+        &self.readers[wire]
+    }
+
+    // Wires no gate drives, which get their values from `evaluate`
+    pub fn inputs(&self) -> Vec<usize> {
+        (0..self.wire_count())
+            .filter(|&wire| self.drivers[wire].is_none())
+            .collect()
+    }
+
+    // The wires named `prefix` followed by a bit number, such as `z00`, as `(bit, wire)` pairs from
+    // the lowest bit up
+    pub fn bus(&self, prefix: char) -> Vec<(usize, usize)> {
+        let mut bus: Vec<(usize, usize)> = self
+            .names
+            .iter()
+            .enumerate()
+            .filter_map(|(wire, name)| {
+                let digits = name.strip_prefix(prefix)?;
+                if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+                    return None;
+                }
+                let bit: usize = digits.parse().ok()?;
+                (bit < usize::BITS as usize).then_some((bit, wire))
+            })
+            .collect();
+        bus.sort();
+        bus
+    }
+
+    // Gates in an order where each one comes after the gates driving its inputs
+    pub fn order(&self) -> &[usize] {
+        &self.order
+    }
+
+    fn sort(&mut self) -> Result<(), SolveError> {
+        let mut unknown_inputs: Vec<usize> = self
+            .gates
+            .iter()
+            .map(|gate| {
+                gate.inputs
+                    .iter()
+                    .filter(|&&input| self.drivers[input].is_some())
+                    .count()
+            })
+            .collect();
+        let mut ready: Vec<usize> = (0..self.gates.len())
+            .filter(|&gate| unknown_inputs[gate] == 0)
+            .collect();
+        let mut order: Vec<usize> = Vec::with_capacity(self.gates.len());
+        while let Some(gate) = ready.pop() {
+            order.push(gate);
+            for &reader in &self.readers[self.gates[gate].output] {
+                unknown_inputs[reader] -= 1;
+                if unknown_inputs[reader] == 0 {
+                    ready.push(reader);
+                }
+            }
+        }
+        if order.len() < self.gates.len() {
+            return Err(SolveError::new(format!(
+                "the gates form a cycle: {}",
+                self.find_cycle(&unknown_inputs).join(" -> ")
+            )));
+        }
+        self.order = order;
+        Ok(())
+    }
+
+    // Every gate left with unknown inputs after sorting has an input driven by another such gate,
+    // so walking back through those inputs has to come round to a gate it already passed
+    fn find_cycle(&self, unknown_inputs: &[usize]) -> Vec<String> {
+        let Some(start) = (0..self.gates.len()).find(|&gate| unknown_inputs[gate] > 0) else {
+            return Vec::new();
+        };
+        let mut path: Vec<usize> = Vec::from([start]);
+        loop {
+            let gate = &self.gates[*path.last().unwrap()];
+            let previous = gate
+                .inputs
+                .iter()
+                .filter_map(|&input| self.drivers[input])
+                .find(|&driver| unknown_inputs[driver] > 0)
+                .unwrap();
+            if let Some(first) = path.iter().position(|&gate| gate == previous) {
+                let mut cycle: Vec<String> = path[first..]
+                    .iter()
+                    .rev()
+                    .map(|&gate| self.names[self.gates[gate].output].clone())
+                    .collect();
+                cycle.push(cycle[0].clone());
+                return cycle;
+            }
+            path.push(previous);
+        }
+    }
+
+    // Exchanges which wires the two gates drive. A swap that would create a cycle is undone
+    pub fn swap_outputs(&mut self, first: usize, second: usize) -> Result<(), SolveError> {
+        let (first_output, second_output) = (self.gates[first].output, self.gates[second].output);
+        self.gates[first].output = second_output;
+        self.gates[second].output = first_output;
+        self.drivers[first_output] = Some(second);
+        self.drivers[second_output] = Some(first);
+        if let Err(cycle_err) = self.sort() {
+            self.gates[first].output = first_output;
+            self.gates[second].output = second_output;
+            self.drivers[first_output] = Some(first);
+            self.drivers[second_output] = Some(second);
+            return Err(cycle_err);
+        }
+        Ok(())
+    }
+
+    // The value of every wire, indexed by wire, given values for the inputs. Inputs left out are
+    // false, and values given for wires a gate drives are overwritten
+    pub fn evaluate(&self, inputs: impl IntoIterator<Item = (usize, bool)>) -> Vec<bool> {
+        let mut values: Vec<bool> = vec![false; self.wire_count()];
+        for (wire, value) in inputs {
+            values[wire] = value;
+        }
+        for &gate in &self.order {
+            let Gate {
+                kind,
+                inputs: [a, b],
+                output,
+            } = self.gates[gate];
+            values[output] = kind.eval(values[a], values[b]);
+        }
+        values
+    }
+}
+
+// Input values setting each wire of `bus` to its bit of `number`
+pub fn bus_bits(bus: &[(usize, usize)], number: usize) -> impl Iterator<Item = (usize, bool)> + '_ {
+    bus.iter()
+        .map(move |&(bit, wire)| (wire, (number >> bit) & 1 == 1))
+}
+
+pub fn read_bus(values: &[bool], bus: &[(usize, usize)]) -> usize {
+    bus.iter()
+        .filter(|&&(_, wire)| values[wire])
+        .fold(0, |acc, &(bit, _)| acc | (1 << bit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn netlist(gates: &[(&str, GateKind, &str, &str)]) -> Result<Netlist, SolveError> {
+        let mut builder = NetlistBuilder::new();
+        for &(a, kind, b, output) in gates {
+            builder.gate(kind, a, b, output).unwrap();
+        }
+        builder.build()
+    }
+
+    fn position(netlist: &Netlist, output: &str) -> usize {
+        let driver = netlist.driver(netlist.wire(output).unwrap()).unwrap();
+        netlist
+            .order()
+            .iter()
+            .position(|&gate| gate == driver)
+            .unwrap()
+    }
+
+    #[test]
+    fn gates_come_after_their_inputs() {
+        let netlist = netlist(&[
+            ("c", GateKind::Xor, "b", "d"),
+            ("a", GateKind::Or, "x", "b"),
+            ("x", GateKind::And, "y", "a"),
+            ("b", GateKind::And, "a", "c"),
+        ])
+        .unwrap();
+        assert_eq!(netlist.order().len(), 4);
+        assert!(position(&netlist, "a") < position(&netlist, "b"));
+        assert!(position(&netlist, "b") < position(&netlist, "c"));
+        assert!(position(&netlist, "c") < position(&netlist, "d"));
+        let inputs: Vec<&str> = netlist
+            .inputs()
+            .into_iter()
+            .map(|wire| netlist.name(wire))
+            .collect();
+        assert_eq!(inputs, ["x", "y"]);
+    }
+
+    #[test]
+    fn a_driven_wire_cannot_be_driven_again() {
+        let mut builder = NetlistBuilder::new();
+        assert_eq!(builder.gate(GateKind::And, "x", "y", "a"), Some(0));
+        assert_eq!(builder.gate(GateKind::Or, "x", "y", "a"), None);
+    }
+
+    #[test]
+    fn cycles_are_reported_by_their_wires() {
+        let err = netlist(&[
+            ("c", GateKind::And, "x", "a"),
+            ("a", GateKind::Or, "y", "b"),
+            ("b", GateKind::Xor, "x", "c"),
+        ])
+        .unwrap_err();
+        assert_eq!(err.message, "the gates form a cycle: b -> c -> a -> b");
+    }
+
+    #[test]
+    fn gates_reading_a_cycle_are_left_out_of_it() {
+        let err = netlist(&[
+            ("b", GateKind::And, "x", "d"),
+            ("b", GateKind::Or, "y", "a"),
+            ("a", GateKind::Xor, "x", "b"),
+        ])
+        .unwrap_err();
+        assert_eq!(err.message, "the gates form a cycle: a -> b -> a");
+    }
+
+    #[test]
+    fn swapping_outputs_rewires_the_gates() {
+        let mut netlist = netlist(&[
+            ("x", GateKind::And, "y", "a"),
+            ("x", GateKind::Or, "y", "b"),
+            ("a", GateKind::Xor, "y", "c"),
+        ])
+        .unwrap();
+        let [x, y, a, b, c] = ["x", "y", "a", "b", "c"].map(|name| netlist.wire(name).unwrap());
+        let values = netlist.evaluate([(x, true), (y, false)]);
+        assert_eq!([values[a], values[b], values[c]], [false, true, false]);
+
+        netlist.swap_outputs(0, 1).unwrap();
+        assert_eq!(netlist.driver(a), Some(1));
+        assert_eq!(netlist.driver(b), Some(0));
+        assert_eq!(netlist.gate(0).output, b);
+        let values = netlist.evaluate([(x, true), (y, false)]);
+        assert_eq!([values[a], values[b], values[c]], [true, false, true]);
+    }
+
+    #[test]
+    fn swaps_that_make_a_cycle_are_undone() {
+        let mut netlist = netlist(&[
+            ("x", GateKind::And, "y", "a"),
+            ("a", GateKind::Or, "y", "b"),
+            ("b", GateKind::Xor, "x", "c"),
+        ])
+        .unwrap();
+        let before = (netlist.gates().to_vec(), netlist.order().to_vec());
+        let err = netlist.swap_outputs(0, 2).unwrap_err();
+        assert!(err.message.starts_with("the gates form a cycle: "));
+        assert_eq!((netlist.gates().to_vec(), netlist.order().to_vec()), before);
+        for (gate, name) in ["a", "b", "c"].into_iter().enumerate() {
+            assert_eq!(netlist.driver(netlist.wire(name).unwrap()), Some(gate));
+        }
+        let [x, y, c] = ["x", "y", "c"].map(|name| netlist.wire(name).unwrap());
+        assert!(netlist.evaluate([(x, false), (y, true)])[c]);
+    }
+
+    #[test]
+    fn buses_are_wires_with_a_prefix_and_a_bit_number() {
+        let netlist = netlist(&[
+            ("x01", GateKind::And, "x00", "z00"),
+            ("x10", GateKind::Or, "xa1", "z01"),
+            ("x", GateKind::Xor, "y00", "z03"),
+        ])
+        .unwrap();
+        let names = |bus: Vec<(usize, usize)>| -> Vec<(usize, String)> {
+            bus.into_iter()
+                .map(|(bit, wire)| (bit, netlist.name(wire).to_string()))
+                .collect()
+        };
+        assert_eq!(
+            names(netlist.bus('x')),
+            [(0, "x00"), (1, "x01"), (10, "x10")].map(|(bit, name)| (bit, name.to_string()))
+        );
+        assert_eq!(
+            names(netlist.bus('z')),
+            [(0, "z00"), (1, "z01"), (3, "z03")].map(|(bit, name)| (bit, name.to_string()))
+        );
+    }
+
+    #[test]
+    fn numbers_go_through_buses() {
+        let netlist = netlist(&[
+            ("x00", GateKind::And, "x00", "z00"),
+            ("x01", GateKind::And, "x01", "z01"),
+            ("x03", GateKind::And, "x03", "z03"),
+        ])
+        .unwrap();
+        let (x_bus, z_bus) = (netlist.bus('x'), netlist.bus('z'));
+        // Bit 2 has no wire, so it's dropped
+        let values = netlist.evaluate(bus_bits(&x_bus, 0b1111));
+        assert_eq!(read_bus(&values, &x_bus), 0b1011);
+        assert_eq!(read_bus(&values, &z_bus), 0b1011);
+        let values = netlist.evaluate(bus_bits(&x_bus, 0b0010));
+        assert_eq!(read_bus(&values, &z_bus), 0b0010);
+    }
+}
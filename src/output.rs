@@ -0,0 +1,219 @@
+use std::{
+    fmt::Display,
+    io::{self, Write},
+    time::Duration,
+};
+
+use clap::ValueEnum;
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Text,
+    Json,
+    Csv,
+}
+
+pub enum Status {
+    Ok,
+    Mismatch,
+    Error,
+    Panicked,
+    TimedOut,
+    MissingInput,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let status = match self {
+            Status::Ok => "ok",
+            Status::Mismatch => "mismatch",
+            Status::Error => "error",
+            Status::Panicked => "panicked",
+            Status::TimedOut => "timed out",
+            Status::MissingInput => "missing input",
+        };
+        f.pad(status)
+    }
+}
+
+// One part of an input as reported by `--all` and `--output`
+pub struct SummaryRow {
+    pub year: u16,
+    pub day: u8,
+    pub name: String,
+    pub part: u8,
+    pub answer: String,
+    pub time: Option<Duration>,
+    pub status: Status,
+}
+
+pub fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// Fields are only quoted when they need to be, e.g. day 17's comma separated answer
+pub fn escape_csv(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+// A failed part has its message in `error` and no answer
+fn answer_and_error(row: &SummaryRow) -> (Option<&str>, Option<&str>) {
+    match row.status {
+        Status::Ok | Status::Mismatch => (Some(row.answer.trim_end()), None),
+        Status::Error | Status::Panicked | Status::TimedOut => (None, Some(row.answer.as_str())),
+        Status::MissingInput => (None, None),
+    }
+}
+
+// Times are written in nanoseconds
+pub fn write_json(rows: &[SummaryRow], writer: &mut impl Write) -> io::Result<()> {
+    let json_string = |value: Option<&str>| match value {
+        Some(value) => format!("\"{}\"", escape_json(value)),
+        None => String::from("null"),
+    };
+    writeln!(writer, "[")?;
+    for (i, row) in rows.iter().enumerate() {
+        let (answer, error) = answer_and_error(row);
+        let time = row
+            .time
+            .map(|time| time.as_nanos().to_string())
+            .unwrap_or(String::from("null"));
+        write!(
+            writer,
+            "  {{\"year\": {}, \"day\": {}, \"input\": \"{}\", \"part\": {}, \"answer\": {}, \"time_ns\": {}, \"status\": \"{}\", \"error\": {}}}",
+            row.year,
+            row.day,
+            escape_json(&row.name),
+            row.part,
+            json_string(answer),
+            time,
+            row.status,
+            json_string(error)
+        )?;
+        writeln!(writer, "{}", if i + 1 < rows.len() { "," } else { "" })?;
+    }
+    writeln!(writer, "]")
+}
+
+pub fn write_csv(rows: &[SummaryRow], writer: &mut impl Write) -> io::Result<()> {
+    writeln!(writer, "year,day,input,part,answer,time_ns,status,error")?;
+    for row in rows {
+        let (answer, error) = answer_and_error(row);
+        let time = row
+            .time
+            .map(|time| time.as_nanos().to_string())
+            .unwrap_or_default();
+        writeln!(
+            writer,
+            "{},{},{},{},{},{},{},{}",
+            row.year,
+            row.day,
+            escape_csv(&row.name),
+            row.part,
+            escape_csv(answer.unwrap_or_default()),
+            time,
+            row.status,
+            escape_csv(error.unwrap_or_default())
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(part: u8, answer: &str, time: Option<Duration>, status: Status) -> SummaryRow {
+        SummaryRow {
+            year: 2024,
+            day: 17,
+            name: String::from("default"),
+            part,
+            answer: answer.to_string(),
+            time,
+            status,
+        }
+    }
+
+    fn rows() -> Vec<SummaryRow> {
+        vec![
+            row(1, "4,6,3\n", Some(Duration::from_nanos(1500)), Status::Ok),
+            row(
+                2,
+                "bad \"input\"",
+                Some(Duration::from_nanos(20)),
+                Status::Error,
+            ),
+            row(1, "", None, Status::MissingInput),
+        ]
+    }
+
+    #[test]
+    fn json_escapes_quotes_backslashes_and_control_characters() {
+        assert_eq!(escape_json("plain"), "plain");
+        assert_eq!(escape_json("say \"hi\""), "say \\\"hi\\\"");
+        assert_eq!(escape_json("C:\\tests"), "C:\\\\tests");
+        assert_eq!(escape_json("a\nb\tc\u{7}"), "a\\u000ab\\u0009c\\u0007");
+        assert_eq!(escape_json("µs → ok"), "µs → ok");
+    }
+
+    #[test]
+    fn csv_quotes_only_fields_that_need_it() {
+        assert_eq!(escape_csv("117440"), "117440");
+        assert_eq!(escape_csv("4,6,3,5,6,3,5,2,1,0"), "\"4,6,3,5,6,3,5,2,1,0\"");
+        assert_eq!(escape_csv("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(escape_csv("two\nlines"), "\"two\nlines\"");
+        assert_eq!(escape_csv(""), "");
+    }
+
+    #[test]
+    fn json_records_use_null_for_missing_values() {
+        let mut out: Vec<u8> = Vec::new();
+        write_json(&rows(), &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            concat!(
+                "[\n",
+                "  {\"year\": 2024, \"day\": 17, \"input\": \"default\", \"part\": 1, \"answer\": \"4,6,3\", \"time_ns\": 1500, \"status\": \"ok\", \"error\": null},\n",
+                "  {\"year\": 2024, \"day\": 17, \"input\": \"default\", \"part\": 2, \"answer\": null, \"time_ns\": 20, \"status\": \"error\", \"error\": \"bad \\\"input\\\"\"},\n",
+                "  {\"year\": 2024, \"day\": 17, \"input\": \"default\", \"part\": 1, \"answer\": null, \"time_ns\": null, \"status\": \"missing input\", \"error\": null}\n",
+                "]\n",
+            )
+        );
+    }
+
+    #[test]
+    fn csv_records_leave_missing_values_empty() {
+        let mut out: Vec<u8> = Vec::new();
+        write_csv(&rows(), &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            concat!(
+                "year,day,input,part,answer,time_ns,status,error\n",
+                "2024,17,default,1,\"4,6,3\",1500,ok,\n",
+                "2024,17,default,2,,20,error,\"bad \"\"input\"\"\"\n",
+                "2024,17,default,1,,,missing input,\n",
+            )
+        );
+    }
+
+    #[test]
+    fn empty_json_output_is_still_an_array() {
+        let mut out: Vec<u8> = Vec::new();
+        write_json(&[], &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "[\n]\n");
+    }
+}
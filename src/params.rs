@@ -0,0 +1,61 @@
+use std::{collections::HashMap, fmt::Display};
+
+use crate::SolveError;
+
+pub struct Param {
+    pub name: &'static str,
+    pub default: u64,
+    pub description: &'static str,
+}
+
+impl Display for Param {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} (default {}): {}",
+            self.name, self.default, self.description
+        )
+    }
+}
+
+#[derive(Clone)]
+pub struct Params {
+    values: HashMap<&'static str, u64>,
+}
+
+impl Params {
+    // Overrides for parameters the solver does not declare are ignored
+    pub fn new(declared: &[Param], overrides: &[(String, u64)]) -> Params {
+        let mut values: HashMap<&'static str, u64> = declared
+            .iter()
+            .map(|param| (param.name, param.default))
+            .collect();
+        for (name, value) in overrides {
+            if let Some(current) = values.get_mut(name.as_str()) {
+                *current = *value;
+            }
+        }
+        Params { values }
+    }
+
+    pub fn get<T>(&self, name: &str) -> Result<T, SolveError>
+    where
+        T: TryFrom<u64>,
+    {
+        let Some(&value) = self.values.get(name) else {
+            return Err(SolveError::new(format!("unknown parameter '{name}'")));
+        };
+        T::try_from(value)
+            .map_err(|_| SolveError::new(format!("parameter {name}={value} is out of range")))
+    }
+}
+
+pub fn parse_override(arg: &str) -> Result<(String, u64), String> {
+    let Some((name, value)) = arg.split_once('=') else {
+        return Err(format!("expected NAME=VALUE, found '{arg}'"));
+    };
+    let value = value
+        .parse()
+        .map_err(|err| format!("invalid value for {name}: {err}"))?;
+    Ok((name.to_string(), value))
+}
@@ -0,0 +1,211 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{parse_token, SolveError};
+
+// Every helper takes the whole puzzle input next to the text it parses, which must be a slice of
+// it, so errors can point at the offending token like `parse_token` does
+
+// Parts of the input separated by blank lines, without the blank lines themselves
+pub fn sections(input: &str) -> Vec<&str> {
+    let mut sections: Vec<&str> = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut end: usize = 0;
+    let mut offset: usize = 0;
+    for line in input.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        if content.trim().is_empty() {
+            if let Some(first) = start.take() {
+                sections.push(&input[first..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + content.len();
+        }
+        offset += line.len();
+    }
+    if let Some(first) = start {
+        sections.push(&input[first..end]);
+    }
+    sections
+}
+
+// Integers anywhere in `line`, e.g. `[94, 34]` from "Button A: X+94, Y+34". A '-' counts as a sign
+// when it comes right before a digit and not right after one
+pub fn signed_integers<T>(input: &str, line: &str) -> Result<Vec<T>, SolveError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let bytes = line.as_bytes();
+    let mut numbers: Vec<T> = Vec::new();
+    let mut i: usize = 0;
+    while i < bytes.len() {
+        let negative = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && (i == 0 || !bytes[i - 1].is_ascii_digit());
+        if !negative && !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        numbers.push(parse_token(input, &line[start..i])?);
+    }
+    Ok(numbers)
+}
+
+// Like `signed_integers`, for lines that always hold the same amount of numbers
+pub fn signed_integer_array<T, const N: usize>(
+    input: &str,
+    line: &str,
+) -> Result<[T; N], SolveError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let numbers: Vec<T> = signed_integers(input, line)?;
+    let found = numbers.len();
+    numbers.try_into().map_err(|_| {
+        SolveError::at_token(input, line, format!("expected {N} numbers, found {found}"))
+    })
+}
+
+// Values separated by `separator`, e.g. "," for "1,2,3" or ", " for "r, wr, b"
+pub fn list<T>(input: &str, text: &str, separator: &str) -> Result<Vec<T>, SolveError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    text.split(separator)
+        .map(|token| parse_token(input, token))
+        .collect()
+}
+
+// Values separated by any amount of whitespace
+pub fn words<T>(input: &str, text: &str) -> Result<Vec<T>, SolveError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    text.split_whitespace()
+        .map(|token| parse_token(input, token))
+        .collect()
+}
+
+// "Key: value" lines, such as the registers on day 17
+pub struct Headers<'a> {
+    input: &'a str,
+    fields: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> Headers<'a> {
+    pub fn parse(input: &'a str, section: &'a str) -> Result<Headers<'a>, SolveError> {
+        let fields = section
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                line.split_once(": ").ok_or_else(|| {
+                    SolveError::at_token(input, line, "expected a line like 'Key: value'")
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Headers { input, fields })
+    }
+
+    // The unparsed value of the first line with `key`
+    pub fn raw(&self, key: &str) -> Result<&'a str, SolveError> {
+        self.fields
+            .iter()
+            .find(|(name, _)| *name == key)
+            .map(|(_, value)| *value)
+            .ok_or_else(|| SolveError::new(format!("missing '{key}'")))
+    }
+
+    pub fn get<T>(&self, key: &str) -> Result<T, SolveError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        parse_token(self.input, self.raw(key)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sections_split_on_blank_lines() {
+        assert_eq!(sections("a\nb\n\nc\n"), Vec::from(["a\nb", "c"]));
+        assert_eq!(sections("\n\na\n  \n\nb"), Vec::from(["a", "b"]));
+        assert_eq!(sections("a\r\nb\r\n\r\nc\r\n"), Vec::from(["a\r\nb", "c"]));
+        assert!(sections("").is_empty());
+    }
+
+    #[test]
+    fn signed_integers_take_signs_only_before_digits() {
+        let line = "Button A: X+94, Y-34";
+        assert_eq!(signed_integers::<i32>(line, line).unwrap(), [94, -34]);
+        let line = "p=0,4 v=3,-3";
+        assert_eq!(signed_integers::<i32>(line, line).unwrap(), [0, 4, 3, -3]);
+        // A '-' between digits is a separator, and one without digits after it is ignored
+        let line = "10-5 - -";
+        assert_eq!(signed_integers::<i32>(line, line).unwrap(), [10, 5]);
+        let line = "-7";
+        assert_eq!(signed_integers::<i32>(line, line).unwrap(), [-7]);
+    }
+
+    #[test]
+    fn signed_integers_report_where_a_number_overflows() {
+        let input = "a: 1\nb: 300";
+        let err = signed_integers::<u8>(input, &input[5..]).unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(4)));
+    }
+
+    #[test]
+    fn signed_integer_array_checks_the_count() {
+        let line = "1 2 3";
+        assert_eq!(
+            signed_integer_array::<i32, 3>(line, line).unwrap(),
+            [1, 2, 3]
+        );
+        let err = signed_integer_array::<i32, 2>(line, line).unwrap_err();
+        assert_eq!(err.message, "expected 2 numbers, found 3");
+    }
+
+    #[test]
+    fn lists_and_words() {
+        let input = "1,2,3\nr, wr, b\n 4   5\t6 ";
+        let lines: Vec<&str> = input.lines().collect();
+        assert_eq!(list::<u8>(input, lines[0], ",").unwrap(), [1, 2, 3]);
+        assert_eq!(
+            list::<String>(input, lines[1], ", ").unwrap(),
+            ["r", "wr", "b"]
+        );
+        assert_eq!(words::<u8>(input, lines[2]).unwrap(), [4, 5, 6]);
+        assert!(list::<u8>(input, lines[1], ",").is_err());
+    }
+
+    #[test]
+    fn headers_look_up_keys() {
+        let input = "Register A: 729\nRegister A: 1\n\nProgram: 0,1";
+        let headers = Headers::parse(input, input).unwrap();
+        assert_eq!(headers.get::<usize>("Register A").unwrap(), 729);
+        assert_eq!(headers.raw("Program").unwrap(), "0,1");
+        assert_eq!(
+            headers.raw("Register B").unwrap_err().message,
+            "missing 'Register B'"
+        );
+        assert!(headers.get::<usize>("Program").is_err());
+    }
+
+    #[test]
+    fn headers_reject_other_lines() {
+        let input = "Register A: 1\nProgram 0,1";
+        let err = Headers::parse(input, input).err().unwrap();
+        assert_eq!((err.line, err.column), (Some(2), Some(1)));
+    }
+}
@@ -0,0 +1,282 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    // Every node one move away from `node`, with the cost of that move
+    fn neighbors(&self, node: &Self::Node) -> Vec<(Self::Node, usize)>;
+}
+
+// Distances from the starts, and every predecessor of a node on one of its shortest paths
+pub struct ShortestPaths<N> {
+    distances: HashMap<N, usize>,
+    predecessors: HashMap<N, Vec<N>>,
+}
+
+impl<N: Clone + Eq + Hash> ShortestPaths<N> {
+    fn new() -> ShortestPaths<N> {
+        ShortestPaths {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+        }
+    }
+
+    // Returns whether `node` got a new shortest distance and has to be explored again
+    fn relax(&mut self, from: Option<&N>, node: &N, distance: usize) -> bool {
+        match self.distances.get(node) {
+            Some(&current) if current < distance => false,
+            Some(&current) if current == distance => {
+                // Starts have no predecessors, and don't get any from zero-cost moves back to them,
+                // so `path_to` always ends at a start
+                if let (Some(from), Some(predecessors)) = (from, self.predecessors.get_mut(node)) {
+                    if !predecessors.is_empty() {
+                        predecessors.push(from.clone());
+                    }
+                }
+                false
+            }
+            _ => {
+                self.distances.insert(node.clone(), distance);
+                self.predecessors
+                    .insert(node.clone(), from.into_iter().cloned().collect());
+                true
+            }
+        }
+    }
+
+    pub fn distance(&self, node: &N) -> Option<usize> {
+        self.distances.get(node).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<N, usize> {
+        &self.distances
+    }
+
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.predecessors.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    // One shortest path from a start to `node`, including both ends
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        self.distances.get(node)?;
+        let mut path = Vec::from([node.clone()]);
+        while let Some(previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    // Every node that lies on any shortest path to one of `ends`
+    pub fn on_shortest_paths(&self, ends: impl IntoIterator<Item = N>) -> HashSet<N> {
+        let mut seen: HashSet<N> = HashSet::new();
+        let mut queue: Vec<N> = ends
+            .into_iter()
+            .filter(|end| self.distances.contains_key(end))
+            .collect();
+        while let Some(node) = queue.pop() {
+            if seen.insert(node.clone()) {
+                queue.extend(self.predecessors(&node).iter().cloned());
+            }
+        }
+        seen
+    }
+}
+
+// Ignores the cost of each move. Stops once every node as close as the nearest goal is reached,
+// so a goal that never matches explores everything reachable
+pub fn bfs<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+) -> ShortestPaths<G::Node> {
+    let mut paths = ShortestPaths::new();
+    let mut queue: VecDeque<(G::Node, usize)> = VecDeque::new();
+    for start in starts {
+        if paths.relax(None, &start, 0) {
+            queue.push_back((start, 0));
+        }
+    }
+    let mut goal_distance: Option<usize> = None;
+    while let Some((node, distance)) = queue.pop_front() {
+        if goal_distance.is_some_and(|goal| distance > goal) {
+            break;
+        }
+        if is_goal(&node) {
+            goal_distance = Some(distance);
+            continue;
+        }
+        for (next, _) in graph.neighbors(&node) {
+            if paths.relax(Some(&node), &next, distance + 1) {
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+    paths
+}
+
+// Stops like `bfs`, once every node as cheap as the cheapest goal is settled
+pub fn dijkstra<G>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+) -> ShortestPaths<G::Node>
+where
+    G: Graph,
+    G::Node: Ord,
+{
+    let mut paths = ShortestPaths::new();
+    let mut heap: BinaryHeap<Reverse<(usize, G::Node)>> = BinaryHeap::new();
+    for start in starts {
+        if paths.relax(None, &start, 0) {
+            heap.push(Reverse((0, start)));
+        }
+    }
+    let mut goal_distance: Option<usize> = None;
+    while let Some(Reverse((distance, node))) = heap.pop() {
+        if goal_distance.is_some_and(|goal| distance > goal) {
+            break;
+        }
+        // Stale entries are left in the heap instead of being updated
+        if paths.distance(&node) != Some(distance) {
+            continue;
+        }
+        if is_goal(&node) {
+            goal_distance = Some(distance);
+            continue;
+        }
+        for (next, cost) in graph.neighbors(&node) {
+            if paths.relax(Some(&node), &next, distance + cost) {
+                heap.push(Reverse((distance + cost, next)));
+            }
+        }
+    }
+    paths
+}
+
+// `heuristic` must never overestimate the remaining cost, or the path found may not be the shortest
+pub fn astar<G>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+    mut heuristic: impl FnMut(&G::Node) -> usize,
+) -> Option<(usize, Vec<G::Node>)>
+where
+    G: Graph,
+    G::Node: Ord,
+{
+    let mut paths = ShortestPaths::new();
+    let mut heap: BinaryHeap<Reverse<(usize, usize, G::Node)>> = BinaryHeap::new();
+    paths.relax(None, &start, 0);
+    heap.push(Reverse((heuristic(&start), 0, start)));
+    while let Some(Reverse((_, distance, node))) = heap.pop() {
+        if paths.distance(&node) != Some(distance) {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some((distance, paths.path_to(&node)?));
+        }
+        for (next, cost) in graph.neighbors(&node) {
+            if paths.relax(Some(&node), &next, distance + cost) {
+                heap.push(Reverse((
+                    distance + cost + heuristic(&next),
+                    distance + cost,
+                    next,
+                )));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Directed edges as `(from, to, cost)`
+    struct Edges(Vec<(u32, u32, usize)>);
+
+    impl Graph for Edges {
+        type Node = u32;
+
+        fn neighbors(&self, node: &u32) -> Vec<(u32, usize)> {
+            self.0
+                .iter()
+                .filter(|(from, _, _)| from == node)
+                .map(|&(_, to, cost)| (to, cost))
+                .collect()
+        }
+    }
+
+    // Two equally short routes from 0 to 3, and a longer one through 4
+    fn diamond() -> Edges {
+        Edges(Vec::from([
+            (0, 1, 1),
+            (0, 2, 1),
+            (1, 3, 1),
+            (2, 3, 1),
+            (0, 4, 1),
+            (4, 5, 1),
+            (5, 3, 1),
+        ]))
+    }
+
+    #[test]
+    fn bfs_counts_moves() {
+        let paths = bfs(&diamond(), [0], |&node| node == 3);
+        assert_eq!(paths.distance(&3), Some(2));
+        assert_eq!(paths.distance(&4), Some(1));
+        assert_eq!(paths.path_to(&3).unwrap().len(), 3);
+        assert_eq!(paths.path_to(&6), None);
+    }
+
+    #[test]
+    fn bfs_without_a_goal_explores_everything() {
+        let paths = bfs(&diamond(), [1, 4], |_| false);
+        assert_eq!(paths.distances().len(), 4);
+        assert_eq!(paths.distance(&3), Some(1));
+        assert_eq!(paths.distance(&0), None);
+    }
+
+    #[test]
+    fn dijkstra_prefers_cheaper_routes() {
+        let graph = Edges(Vec::from([(0, 1, 10), (0, 2, 1), (2, 1, 2), (1, 3, 1)]));
+        let paths = dijkstra(&graph, [0], |&node| node == 3);
+        assert_eq!(paths.distance(&3), Some(4));
+        assert_eq!(paths.path_to(&3), Some(Vec::from([0, 2, 1, 3])));
+    }
+
+    #[test]
+    fn zero_cost_moves_back_to_a_start_end_the_path() {
+        let graph = Edges(Vec::from([(0, 1, 0), (1, 0, 0), (1, 2, 1)]));
+        let paths = dijkstra(&graph, [0], |&node| node == 2);
+        assert_eq!(paths.distance(&2), Some(1));
+        assert!(paths.predecessors(&0).is_empty());
+        assert_eq!(paths.path_to(&2), Some(Vec::from([0, 1, 2])));
+        assert_eq!(paths.on_shortest_paths([2]), HashSet::from([0, 1, 2]));
+    }
+
+    #[test]
+    fn astar_finds_the_shortest_path() {
+        let graph = Edges(Vec::from([(0, 1, 1), (1, 2, 1), (0, 2, 5), (2, 3, 1)]));
+        let remaining = |node: &u32| 3 - *node as usize;
+        assert_eq!(
+            astar(&graph, 0, |&node| node == 3, remaining),
+            Some((3, Vec::from([0, 1, 2, 3])))
+        );
+        assert_eq!(astar(&graph, 3, |&node| node == 0, |_| 0), None);
+    }
+
+    #[test]
+    fn on_shortest_paths_keeps_every_tied_route() {
+        let paths = dijkstra(&diamond(), [0], |&node| node == 3);
+        assert_eq!(paths.on_shortest_paths([3]), HashSet::from([0, 1, 2, 3]));
+        let mut predecessors = paths.predecessors(&3).to_vec();
+        predecessors.sort();
+        assert_eq!(predecessors, Vec::from([1, 2]));
+    }
+}
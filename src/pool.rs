@@ -0,0 +1,49 @@
+use std::{
+    num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+// Solvers may keep large arrays on the stack, which debug builds don't optimise away, so workers get
+// more than the 2 MiB a spawned thread has by default
+pub const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+// Runs `f` on every item using up to `jobs` threads, and returns the results in the order of `items`
+pub fn map<T, R, F>(items: &[T], jobs: NonZeroUsize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let workers = jobs.get().min(items.len());
+    if workers <= 1 {
+        return items.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<R>>> = Mutex::new(items.iter().map(|_| None).collect());
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            thread::Builder::new()
+                .stack_size(STACK_SIZE)
+                .spawn_scoped(scope, || loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(i) else {
+                        break;
+                    };
+                    let result = f(item);
+                    results.lock().unwrap()[i] = Some(result);
+                })
+                .expect("failed to spawn a worker thread");
+        }
+    });
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("every item is taken by a worker"))
+        .collect()
+}
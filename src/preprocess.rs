@@ -0,0 +1,99 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    ByteOrderMark,
+    CrlfLineEndings,
+    TrailingWhitespace,
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let description = match self {
+            Change::ByteOrderMark => "removed a byte order mark",
+            Change::CrlfLineEndings => "converted CRLF line endings",
+            Change::TrailingWhitespace => "stripped trailing whitespace",
+        };
+        write!(f, "{description}")
+    }
+}
+
+pub struct Normalized {
+    pub text: String,
+    pub changes: Vec<Change>,
+}
+
+// Inputs saved by editors and browsers differ in ways solvers shouldn't care about, so every line
+// ends in a bare '\n' except the last one, which has no line ending at all
+pub fn normalize(raw: &str) -> Normalized {
+    let mut changes: Vec<Change> = Vec::new();
+    let mut text = raw;
+    if let Some(stripped) = text.strip_prefix('\u{feff}') {
+        changes.push(Change::ByteOrderMark);
+        text = stripped;
+    }
+    if text.contains("\r\n") {
+        changes.push(Change::CrlfLineEndings);
+    }
+
+    let lines: Vec<&str> = text.lines().map(str::trim_end).collect();
+    let mut normalized = lines.join("\n");
+    normalized.truncate(normalized.trim_end().len());
+    // A single final line ending is expected, so dropping it isn't worth a warning
+    let unix = text.replace("\r\n", "\n");
+    if normalized != unix.strip_suffix('\n').unwrap_or(&unix) {
+        changes.push(Change::TrailingWhitespace);
+    }
+    Normalized {
+        text: normalized,
+        changes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_single_final_newline_is_dropped_quietly() {
+        let normalized = normalize("1 2\n3 4\n");
+        assert_eq!(normalized.text, "1 2\n3 4");
+        assert!(normalized.changes.is_empty());
+        assert!(normalize("1 2").changes.is_empty());
+    }
+
+    #[test]
+    fn extra_blank_lines_at_the_end_are_trailing_whitespace() {
+        let normalized = normalize("1 2\n\n\n");
+        assert_eq!(normalized.text, "1 2");
+        assert_eq!(normalized.changes, [Change::TrailingWhitespace]);
+    }
+
+    #[test]
+    fn trailing_spaces_are_stripped_from_every_line() {
+        let normalized = normalize("a \nb\t\n\n  c");
+        assert_eq!(normalized.text, "a\nb\n\n  c");
+        assert_eq!(normalized.changes, [Change::TrailingWhitespace]);
+    }
+
+    #[test]
+    fn crlf_keeps_blank_lines() {
+        let normalized = normalize("a\r\n\r\nb\r\n");
+        assert_eq!(normalized.text, "a\n\nb");
+        assert_eq!(normalized.changes, [Change::CrlfLineEndings]);
+    }
+
+    #[test]
+    fn byte_order_marks_are_removed() {
+        let normalized = normalize("\u{feff}a\r\nb  \r\n");
+        assert_eq!(normalized.text, "a\nb");
+        assert_eq!(
+            normalized.changes,
+            [
+                Change::ByteOrderMark,
+                Change::CrlfLineEndings,
+                Change::TrailingWhitespace
+            ]
+        );
+    }
+}
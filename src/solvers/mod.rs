@@ -1,58 +1,75 @@
 use crate::Solver;
 
-mod day1;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day2;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-
-pub fn get_solver(day: u8) -> Option<Box<dyn Solver>> {
-    match day {
-        1 => Some(Box::new(day1::Solver {})),
-        2 => Some(Box::new(day2::Solver {})),
-        3 => Some(Box::new(day3::Solver {})),
-        4 => Some(Box::new(day4::Solver {})),
-        5 => Some(Box::new(day5::Solver {})),
-        6 => Some(Box::new(day6::Solver {})),
-        7 => Some(Box::new(day7::Solver {})),
-        8 => Some(Box::new(day8::Solver {})),
-        9 => Some(Box::new(day9::Solver {})),
-        10 => Some(Box::new(day10::Solver {})),
-        11 => Some(Box::new(day11::Solver {})),
-        12 => Some(Box::new(day12::Solver {})),
-        13 => Some(Box::new(day13::Solver {})),
-        14 => Some(Box::new(day14::Solver {})),
-        15 => Some(Box::new(day15::Solver {})),
-        16 => Some(Box::new(day16::Solver {})),
-        17 => Some(Box::new(day17::Solver {})),
-        18 => Some(Box::new(day18::Solver {})),
-        19 => Some(Box::new(day19::Solver {})),
-        20 => Some(Box::new(day20::Solver {})),
-        21 => Some(Box::new(day21::Solver {})),
-        22 => Some(Box::new(day22::Solver {})),
-        23 => Some(Box::new(day23::Solver {})),
-        24 => Some(Box::new(day24::Solver {})),
-        25 => Some(Box::new(day25::Solver {})),
-        _ => None,
+pub struct Registration {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub parts: &'static [u8],
+    pub solver: fn() -> Box<dyn Solver>,
+}
+
+impl Registration {
+    pub fn supports(&self, part: u8) -> bool {
+        self.parts.contains(&part)
+    }
+}
+
+/// Builds a year's `REGISTRY` from the `REGISTRATION` each of its day modules declares.
+///
+/// Year modules don't call this themselves. `build.rs` reads the `pub mod dayN;` lines of each
+/// `yYYYY/mod.rs` and writes the call into `OUT_DIR/yYYYY_registry.rs`, which the year module
+/// `include!`s, so declaring the module is all it takes to register a day. The module lines stay
+/// plain items, where rustfmt can see them, but a day declared any other way, such as behind a
+/// `#[cfg]` or with a different name, is not picked up.
+macro_rules! register {
+    ($($day:ident),* $(,)?) => {
+        pub static REGISTRY: &[&Registration] = &[$(&$day::REGISTRATION),*];
+    };
+}
+
+pub mod y2024;
+
+static YEARS: &[&[&Registration]] = &[y2024::REGISTRY];
+
+pub fn iter() -> impl Iterator<Item = &'static Registration> {
+    YEARS.iter().flat_map(|year| year.iter().copied())
+}
+
+pub fn list() -> Vec<&'static Registration> {
+    let mut registrations: Vec<&'static Registration> = iter().collect();
+    registrations.sort_by_key(|registration| (registration.year, registration.day));
+    registrations
+}
+
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = iter().map(|registration| registration.year).collect();
+    years.sort();
+    years.dedup();
+    years
+}
+
+pub fn latest_year() -> Option<u16> {
+    years().last().copied()
+}
+
+pub fn get(year: u16, day: u8) -> Option<&'static Registration> {
+    iter().find(|registration| registration.year == year && registration.day == day)
+}
+
+pub fn get_solver(year: u16, day: u8) -> Option<Box<dyn Solver>> {
+    get(year, day).map(|registration| (registration.solver)())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_declared_day_is_registered_once() {
+        let days: Vec<(u16, u8)> = list()
+            .iter()
+            .map(|registration| (registration.year, registration.day))
+            .collect();
+        assert_eq!(days, (1..=25).map(|day| (2024, day)).collect::<Vec<_>>());
     }
 }
@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+
+use crate::{parse, solvers::Registration, Params, SolveError};
+
+pub struct Solver {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2024,
+    day: 1,
+    title: "Historian Hysteria",
+    parts: &[1, 2],
+    solver: || Box::new(Solver {}),
+};
+
+fn parse_lists(input: &str) -> Result<(Vec<usize>, Vec<usize>), SolveError> {
+    let mut list_a: Vec<usize> = Vec::new();
+    let mut list_b: Vec<usize> = Vec::new();
+    for line in input.lines() {
+        if line.is_empty() {
+            break;
+        }
+        let nums: Vec<usize> = parse::words(input, line)?;
+        let [a, b] = nums[..] else {
+            return Err(SolveError::at_token(
+                input,
+                line,
+                format!("expected two location IDs, found {}", nums.len()),
+            ));
+        };
+        list_a.push(a);
+        list_b.push(b);
+    }
+    Ok((list_a, list_b))
+}
+
+pub fn part1(input: &str) -> Result<usize, SolveError> {
+    let (mut list_a, mut list_b) = parse_lists(input)?;
+    list_a.sort();
+    list_b.sort();
+    let distance: usize = list_a
+        .iter()
+        .zip(list_b.iter())
+        .map(|(a, b)| a.abs_diff(*b))
+        .sum();
+    Ok(distance)
+}
+
+pub fn part2(input: &str) -> Result<usize, SolveError> {
+    let (list_a, list_b) = parse_lists(input)?;
+    let mut count_a: HashMap<usize, usize> = HashMap::new();
+    let mut count_b: HashMap<usize, usize> = HashMap::new();
+    for a in list_a {
+        count_a.insert(a, count_a.get(&a).unwrap_or(&0) + 1);
+    }
+    for b in list_b {
+        count_b.insert(b, count_b.get(&b).unwrap_or(&0) + 1);
+    }
+    let mut similarity: usize = 0;
+    for (a, count) in &count_a {
+        similarity += a * count * count_b.get(a).unwrap_or(&0);
+    }
+    Ok(similarity)
+}
+
+impl crate::Solver for Solver {
+    fn solve_part1(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn solve_part2(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part2(input)?.to_string())
+    }
+}
@@ -0,0 +1,92 @@
+use std::collections::HashSet;
+
+use crate::{geometry::Point, grid::Grid, solvers::Registration, Params, SolveError};
+
+pub struct Solver {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2024,
+    day: 10,
+    title: "Hoof It",
+    parts: &[1, 2],
+    solver: || Box::new(Solver {}),
+};
+
+fn parse_map(input: &str) -> Result<Grid<u8>, SolveError> {
+    Grid::parse_with(input, |token, c| {
+        c.to_digit(10).map(|h| h as u8).ok_or_else(|| {
+            SolveError::at_token(
+                input,
+                token,
+                format!("expected a height digit, found {c:?}"),
+            )
+        })
+    })
+}
+
+fn uphill_neighbours(at: Point, map: &Grid<u8>) -> impl Iterator<Item = Point> + '_ {
+    let current_height = map[at];
+    map.neighbors4(at)
+        .filter(move |next| map[*next] == current_height + 1)
+}
+
+pub fn part1(input: &str) -> Result<usize, SolveError> {
+    let map = parse_map(input)?;
+
+    fn search(at: Point, map: &Grid<u8>, peaks: &mut HashSet<Point>) {
+        if map[at] == 9 {
+            peaks.insert(at);
+            return;
+        }
+        for next in uphill_neighbours(at, map) {
+            search(next, map, peaks);
+        }
+    }
+
+    let acc: usize = map
+        .find_all(&0)
+        .map(|pos| {
+            let mut peaks = HashSet::new();
+            search(pos, &map, &mut peaks);
+            peaks.len()
+        })
+        .sum();
+    Ok(acc)
+}
+
+pub fn part2(input: &str) -> Result<usize, SolveError> {
+    let map = parse_map(input)?;
+    let mut ways_to_reach: Grid<Option<usize>> = map.map(|h| match *h {
+        9 => Some(1),
+        _ => None,
+    });
+
+    fn search(at: Point, map: &Grid<u8>, cache: &mut Grid<Option<usize>>) -> usize {
+        if let Some(cached) = cache[at] {
+            return cached;
+        }
+
+        let mut total_ways: usize = 0;
+        for next in uphill_neighbours(at, map) {
+            total_ways += search(next, map, cache);
+        }
+        cache[at] = Some(total_ways);
+        total_ways
+    }
+
+    let acc: usize = map
+        .find_all(&0)
+        .map(|pos| search(pos, &map, &mut ways_to_reach))
+        .sum();
+    Ok(acc)
+}
+
+impl crate::Solver for Solver {
+    fn solve_part1(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn solve_part2(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part2(input)?.to_string())
+    }
+}
@@ -0,0 +1,171 @@
+use std::{
+    cmp::min,
+    collections::{BinaryHeap, HashMap},
+};
+
+use crate::{parse, solvers::Registration, Param, Params, SolveError};
+
+pub struct Solver {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2024,
+    day: 11,
+    title: "Plutonian Pebbles",
+    parts: &[1, 2],
+    solver: || Box::new(Solver {}),
+};
+
+#[derive(PartialEq, Eq)]
+struct EvolvePair {
+    steps_needed: usize,
+    val: usize,
+    weight: usize,
+}
+
+impl PartialOrd for EvolvePair {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for EvolvePair {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match self.steps_needed.cmp(&other.steps_needed) {
+            std::cmp::Ordering::Less => std::cmp::Ordering::Greater,
+            std::cmp::Ordering::Equal => match self.val.cmp(&other.val) {
+                std::cmp::Ordering::Less => std::cmp::Ordering::Less,
+                std::cmp::Ordering::Equal => self.weight.cmp(&other.weight),
+                std::cmp::Ordering::Greater => std::cmp::Ordering::Greater,
+            },
+            std::cmp::Ordering::Greater => std::cmp::Ordering::Less,
+        }
+    }
+}
+
+fn count_stones(input: &str, blinks: usize) -> Result<usize, SolveError> {
+    let arrangement: Vec<usize> = parse::list(input, input, " ")?;
+    let mut cache: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+
+    fn evolve(
+        stone: usize,
+        steps: usize,
+        cache: &mut HashMap<(usize, usize), Vec<usize>>,
+    ) -> Vec<usize> {
+        if let Some(result) = cache.get(&(stone, steps)) {
+            return result.clone();
+        }
+
+        if steps == 0 {
+            return Vec::from([stone]);
+        }
+
+        for step in steps - 1..=0 {
+            let mut partials: Vec<usize> = Vec::new();
+            if let Some(partial_result) = cache.get(&(stone, steps)) {
+                partials.extend(partial_result);
+            }
+            if !partials.is_empty() {
+                let mut result: Vec<usize> = Vec::new();
+                for partial in partials {
+                    let expanded = evolve(partial, steps - step, cache);
+                    result.extend(expanded);
+                }
+                cache.insert((stone, steps), result.clone());
+                return result;
+            }
+        }
+
+        if stone == 0 {
+            let result = evolve(1, steps - 1, cache);
+            cache.insert((stone, steps), result.clone());
+            result
+        } else {
+            let num_digits = stone.ilog10() + 1;
+            if num_digits.is_multiple_of(2) {
+                let top_digits = stone / 10_usize.pow(num_digits / 2);
+                let bottom_digits = stone - top_digits * 10_usize.pow(num_digits / 2);
+                let result = Vec::from_iter(
+                    evolve(top_digits, steps - 1, cache)
+                        .iter()
+                        .copied()
+                        .chain(evolve(bottom_digits, steps - 1, cache).iter().copied()),
+                );
+                cache.insert((stone, steps), result.clone());
+                result
+            } else {
+                let result = evolve(stone * 2024, steps - 1, cache);
+                cache.insert((stone, steps), result.clone());
+                result
+            }
+        }
+    }
+
+    let mut compute_queue = BinaryHeap::from_iter(arrangement.iter().map(|s| EvolvePair {
+        steps_needed: blinks,
+        val: *s,
+        weight: 1,
+    }));
+    let mut acc = 0;
+    let limit = 25;
+
+    while let Some(to_comp) = compute_queue.pop() {
+        let target_steps = min(to_comp.steps_needed, limit);
+        let partial = evolve(to_comp.val, target_steps, &mut cache);
+        // println!("{} {} {}", to_comp.steps_needed, to_comp.val, to_comp.weight);
+        if target_steps == to_comp.steps_needed {
+            acc += to_comp.weight * partial.len();
+            // println!("{:#?}", partial);
+        } else {
+            let mut count: HashMap<usize, usize> = HashMap::new();
+            for s in partial {
+                let current = count.get(&s).unwrap_or(&0);
+                count.insert(s, current + 1);
+            }
+            for (val, weight) in count {
+                compute_queue.push(EvolvePair {
+                    steps_needed: to_comp.steps_needed - target_steps,
+                    val,
+                    weight: to_comp.weight * weight,
+                });
+            }
+        }
+    }
+
+    // println!("{:#?}", arrangement);
+    Ok(acc)
+}
+
+pub fn part1(input: &str, blinks: usize) -> Result<usize, SolveError> {
+    count_stones(input, blinks)
+}
+
+pub fn part2(input: &str, blinks: usize) -> Result<usize, SolveError> {
+    count_stones(input, blinks)
+}
+
+const PARAMS: [Param; 2] = [
+    Param {
+        name: "part1_blinks",
+        default: 25,
+        description: "Number of blinks in part 1",
+    },
+    Param {
+        name: "part2_blinks",
+        default: 75,
+        description: "Number of blinks in part 2",
+    },
+];
+
+impl crate::Solver for Solver {
+    fn params(&self) -> &'static [Param] {
+        &PARAMS
+    }
+
+    fn solve_part1(&self, input: &str, params: &Params) -> Result<String, SolveError> {
+        Ok(part1(input, params.get("part1_blinks")?)?.to_string())
+    }
+
+    fn solve_part2(&self, input: &str, params: &Params) -> Result<String, SolveError> {
+        Ok(part2(input, params.get("part2_blinks")?)?.to_string())
+    }
+}
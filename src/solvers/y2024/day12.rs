@@ -0,0 +1,150 @@
+use std::collections::HashSet;
+
+use crate::{
+    geometry::{Direction, Point},
+    grid::Grid,
+    solvers::Registration,
+    Params, SolveError,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Fence {
+    position: Point,
+    side: Direction,
+}
+
+impl Fence {
+    fn is_connected_to(&self, other: &Fence) -> bool {
+        if self.side != other.side {
+            return false;
+        }
+        // Fences on the same side of a region run at a right angle to it
+        let along = self.side.rotate_cw();
+        self.position.step(along) == Some(other.position)
+            || self.position.step(along.reverse()) == Some(other.position)
+    }
+}
+
+pub struct Solver {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2024,
+    day: 12,
+    title: "Garden Groups",
+    parts: &[1, 2],
+    solver: || Box::new(Solver {}),
+};
+
+struct Region {
+    area: usize,
+    fences: Vec<Fence>,
+}
+
+impl Region {
+    fn count_sides(&self) -> usize {
+        let mut sides: Vec<Vec<&Fence>> = Vec::new();
+
+        for fence in &self.fences {
+            let mut adj_indices: Vec<usize> = Vec::new();
+            for (index, side) in sides.iter().enumerate() {
+                for other in side {
+                    if other.is_connected_to(fence) {
+                        adj_indices.push(index);
+                    }
+                }
+            }
+
+            match adj_indices.len() {
+                0 => {
+                    sides.push(Vec::from([fence]));
+                }
+                1 => {
+                    sides[adj_indices[0]].push(fence);
+                }
+                _ => {
+                    adj_indices.sort();
+                    let mut merged = sides.remove(adj_indices.pop().unwrap());
+                    while let Some(index) = adj_indices.pop() {
+                        merged.extend(sides.remove(index));
+                    }
+                    sides.push(merged);
+                }
+            }
+        }
+
+        sides.len()
+    }
+}
+
+fn explore_region(start: Point, map: &Grid<char>, visited: &mut Grid<bool>) -> Region {
+    let symbol: char = map[start];
+    let mut region: HashSet<Point> = HashSet::new();
+    let mut explore_queue: Vec<Point> = Vec::from([start]);
+    let mut fences: Vec<Fence> = Vec::new();
+
+    while let Some(pos) = explore_queue.pop() {
+        if region.contains(&pos) {
+            continue;
+        }
+
+        assert!(map[pos] == symbol);
+
+        region.insert(pos);
+        visited[pos] = true;
+        for side in Direction::ALL {
+            match map.step(pos, side) {
+                Some(next) if region.contains(&next) => (),
+                Some(next) if map[next] == symbol => explore_queue.push(next),
+                _ => fences.push(Fence {
+                    position: pos,
+                    side,
+                }),
+            }
+        }
+    }
+
+    Region {
+        area: region.len(),
+        fences,
+    }
+}
+
+fn find_regions(input: &str) -> Result<Vec<Region>, SolveError> {
+    let map = Grid::parse(input)?;
+    let mut visited: Grid<bool> = map.map(|_| false);
+    let mut regions: Vec<Region> = Vec::new();
+
+    for pos in map.positions() {
+        if !visited[pos] {
+            regions.push(explore_region(pos, &map, &mut visited));
+        }
+    }
+
+    Ok(regions)
+}
+
+pub fn part1(input: &str) -> Result<usize, SolveError> {
+    let acc: usize = find_regions(input)?
+        .iter()
+        .map(|region| region.area * region.fences.len())
+        .sum();
+    Ok(acc)
+}
+
+pub fn part2(input: &str) -> Result<usize, SolveError> {
+    let acc: usize = find_regions(input)?
+        .iter()
+        .map(|region| region.area * region.count_sides())
+        .sum();
+    Ok(acc)
+}
+
+impl crate::Solver for Solver {
+    fn solve_part1(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn solve_part2(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part2(input)?.to_string())
+    }
+}
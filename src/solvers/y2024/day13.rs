@@ -0,0 +1,80 @@
+use crate::{parse, solvers::Registration, Param, Params, SolveError};
+
+pub struct Solver {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2024,
+    day: 13,
+    title: "Claw Contraption",
+    parts: &[1, 2],
+    solver: || Box::new(Solver {}),
+};
+
+fn total_tokens(input: &str, prize_offset: isize) -> Result<usize, SolveError> {
+    // v1 = x1a + y1b
+    // v2 = x2a + y2b
+    // Some algebra later...
+    // b = (x1 * v2 - x2 * v1) / (x1 * y2 - x2 * y1)
+    let mut acc: usize = 0;
+    for section in parse::sections(input) {
+        let [button_a, button_b, prize] = section.lines().collect::<Vec<&str>>()[..] else {
+            return Err(SolveError::at_token(
+                input,
+                section,
+                "expected a claw machine with two buttons and a prize",
+            ));
+        };
+        let [ax, ay]: [isize; 2] = parse::signed_integer_array(input, button_a)?;
+        let [bx, by]: [isize; 2] = parse::signed_integer_array(input, button_b)?;
+        let [px, py]: [isize; 2] = parse::signed_integer_array(input, prize)?;
+        let button_a = (ax, ay);
+        let button_b = (bx, by);
+        let prize: (isize, isize) = (prize_offset + px, prize_offset + py);
+
+        let b_denom = button_a.0 * button_b.1 - button_a.1 * button_b.0;
+        let b_numer = button_a.0 * prize.1 - button_a.1 * prize.0;
+
+        // a and b seem to always be linearly independant, so no need to check
+        if let Some(b_candidate) = b_numer.checked_div(b_denom) {
+            if let Some(a_candidate) = (prize.0 - b_candidate * button_b.0).checked_div(button_a.0)
+            {
+                if prize.0 == button_a.0 * a_candidate + button_b.0 * b_candidate
+                    && prize.1 == button_a.1 * a_candidate + button_b.1 * b_candidate
+                    && a_candidate >= 0
+                    && b_candidate >= 0
+                {
+                    acc += a_candidate as usize * 3 + b_candidate as usize;
+                }
+            }
+        }
+    }
+    Ok(acc)
+}
+
+pub fn part1(input: &str) -> Result<usize, SolveError> {
+    total_tokens(input, 0)
+}
+
+pub fn part2(input: &str, prize_offset: isize) -> Result<usize, SolveError> {
+    total_tokens(input, prize_offset)
+}
+
+const PARAMS: [Param; 1] = [Param {
+    name: "prize_offset",
+    default: 10000000000000,
+    description: "Distance added to both prize coordinates in part 2",
+}];
+
+impl crate::Solver for Solver {
+    fn params(&self) -> &'static [Param] {
+        &PARAMS
+    }
+
+    fn solve_part1(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn solve_part2(&self, input: &str, params: &Params) -> Result<String, SolveError> {
+        Ok(part2(input, params.get("prize_offset")?)?.to_string())
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{
+    diagnostics,
+    geometry::Point,
+    grid::Grid,
+    parse,
+    solvers::Registration,
+    visualize::{self, Frame},
+    Param, Params, SolveError,
+};
+
+pub struct Solver {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2024,
+    day: 14,
+    title: "Restroom Redoubt",
+    parts: &[1, 2],
+    solver: || Box::new(Solver {}),
+};
+
+struct Robot {
+    x: isize,
+    y: isize,
+    vel_x: isize,
+    vel_y: isize,
+    width: isize,
+    height: isize,
+    location_cache: Vec<(isize, isize)>,
+    period: Option<usize>,
+}
+
+impl Robot {
+    fn new(x: isize, y: isize, vel_x: isize, vel_y: isize, width: isize, height: isize) -> Robot {
+        Robot {
+            x,
+            y,
+            vel_x,
+            vel_y,
+            width,
+            height,
+            location_cache: Vec::from([(x, y)]),
+            period: None,
+        }
+    }
+
+    fn solve(&mut self, step: usize) -> (isize, isize) {
+        // Should have a &self version that does not update the cache
+        if let Some(period) = self.period {
+            self.location_cache[step % period]
+        } else if let Some(cached) = self.location_cache.get(step) {
+            *cached
+        } else {
+            while self.location_cache.len() <= step {
+                let mut next = *self.location_cache.last().unwrap();
+                next.0 += self.vel_x;
+                next.1 += self.vel_y;
+                if next.0 < 0 {
+                    next.0 += self.width;
+                } else if next.0 >= self.width {
+                    next.0 -= self.width;
+                }
+
+                if next.1 < 0 {
+                    next.1 += self.height;
+                } else if next.1 >= self.height {
+                    next.1 -= self.height;
+                }
+
+                if next == (self.x, self.y) {
+                    self.period = Some(self.location_cache.len());
+                    break;
+                } else {
+                    self.location_cache.push(next);
+                }
+            }
+            self.solve(step)
+        }
+    }
+}
+
+fn parse_robots(input: &str, width: isize, height: isize) -> Result<Vec<Robot>, SolveError> {
+    let mut robots: Vec<Robot> = Vec::new();
+
+    for line in input.lines() {
+        let [px, py, vx, vy]: [isize; 4] = parse::signed_integer_array(input, line)?;
+        let pos = (px, py);
+        let vel = (vx, vy);
+        if !(0..width).contains(&pos.0)
+            || !(0..height).contains(&pos.1)
+            || vel.0.abs() >= width
+            || vel.1.abs() >= height
+        {
+            return Err(SolveError::at_token(
+                input,
+                line,
+                format!("robot does not fit in a {width}x{height} space"),
+            ));
+        }
+
+        robots.push(Robot::new(pos.0, pos.1, vel.0, vel.1, width, height));
+    }
+
+    Ok(robots)
+}
+
+pub fn part1(input: &str, width: isize, height: isize) -> Result<usize, SolveError> {
+    let mut quadrants: [usize; 4] = [0; 4];
+    for (x, y) in parse_robots(input, width, height)?
+        .iter_mut()
+        .map(|robot| robot.solve(100))
+    {
+        if x == width / 2 || y == height / 2 {
+            continue;
+        }
+        let index = (x > width / 2) as usize + 2 * (y > height / 2) as usize;
+        quadrants[index] += 1;
+    }
+    Ok(quadrants.iter().product::<usize>())
+}
+
+pub fn part2(input: &str, width: isize, height: isize) -> Result<usize, SolveError> {
+    let mut robots = parse_robots(input, width, height)?;
+    let mut draw: Grid<bool>;
+
+    let mut best: Option<(usize, usize)> = None;
+
+    for step in 0..(width * height) as usize {
+        // One full period
+
+        draw = Grid::new(width as usize, height as usize, false);
+
+        for (x, y) in robots.iter_mut().map(|robot| robot.solve(step)) {
+            draw[Point::new(x as usize, y as usize)] = true;
+        }
+
+        let mut score: usize = 0;
+
+        for x in 1..width - 1 {
+            for y in 1..height - 1 {
+                let pos = Point::new(x as usize, y as usize);
+                if !draw[pos] {
+                    continue;
+                }
+                let inner_score = draw.neighbors4(pos).filter(|next| draw[*next]).count();
+
+                score += inner_score * inner_score;
+            }
+        }
+
+        if let Some((_, best_score)) = best {
+            if score > best_score {
+                best = Some((step, score));
+            }
+        } else {
+            best = Some((step, score));
+        }
+    }
+
+    draw = Grid::new(width as usize, height as usize, false);
+
+    let Some((best_step, _)) = best else {
+        return Err(SolveError::new("the space has no room for robots"));
+    };
+
+    for (x, y) in robots.iter_mut().map(|robot| robot.solve(best_step)) {
+        draw[Point::new(x as usize, y as usize)] = true;
+    }
+
+    let picture = Frame::from_rows(
+        draw.rows()
+            .map(|row| row.iter().map(|full| if *full { 'O' } else { ' ' })),
+    );
+    diagnostics::emit(picture.to_string().trim_end_matches('\n'));
+    visualize::frame(|| picture);
+
+    Ok(best_step)
+}
+
+const PARAMS: [Param; 2] = [
+    Param {
+        name: "width",
+        default: 101,
+        description: "Width of the space the robots move in",
+    },
+    Param {
+        name: "height",
+        default: 103,
+        description: "Height of the space the robots move in",
+    },
+];
+
+impl crate::Solver for Solver {
+    fn params(&self) -> &'static [Param] {
+        &PARAMS
+    }
+
+    fn solve_part1(&self, input: &str, params: &Params) -> Result<String, SolveError> {
+        Ok(part1(input, params.get("width")?, params.get("height")?)?.to_string())
+    }
+
+    fn solve_part2(&self, input: &str, params: &Params) -> Result<String, SolveError> {
+        Ok(part2(input, params.get("width")?, params.get("height")?)?.to_string())
+    }
+}
@@ -0,0 +1,324 @@
+use std::collections::HashMap;
+
+use crate::{
+    geometry::{Direction, Point},
+    grid::Grid,
+    solvers::Registration,
+    visualize::{self, Frame},
+    Params, SolveError,
+};
+
+pub struct Solver {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2024,
+    day: 15,
+    title: "Warehouse Woes",
+    parts: &[1, 2],
+    solver: || Box::new(Solver {}),
+};
+
+struct Robot {
+    pos: Point,
+}
+
+fn push_box(at: Point, direction: Direction, map: &mut Grid<char>) {
+    let mut to_push: Vec<Point> = Vec::new();
+
+    match direction {
+        Direction::West => {
+            to_push.push(Point::new(at.x - 1, at.y));
+        }
+        Direction::East => {
+            to_push.push(Point::new(at.x + 2, at.y));
+        }
+        Direction::North | Direction::South => {
+            let ahead = at + direction.vector();
+            to_push.push(ahead);
+            to_push.push(Point::new(ahead.x + 1, ahead.y));
+        }
+    }
+
+    while let Some(push_at) = to_push.pop() {
+        match map[push_at] {
+            ']' => {
+                to_push.push(Point::new(push_at.x - 1, push_at.y));
+            }
+            '[' => {
+                push_box(push_at, direction, map);
+            }
+            '.' => (),
+            c => unreachable!("Found {c} {:?}", push_at), // Path should be clear
+        }
+    }
+    let right = Point::new(at.x + 1, at.y);
+    let step = direction.vector();
+    map[at] = '.';
+    map[right] = '.';
+    assert!(map[at + step] == '.');
+    assert!(map[right + step] == '.');
+    map[at + step] = '[';
+    map[right + step] = ']';
+}
+
+fn check_box(
+    at: Point,
+    direction: Direction,
+    map: &Grid<char>,
+    ok_cache: &mut HashMap<Point, bool>,
+) -> bool {
+    if let Some(cached) = ok_cache.get(&at) {
+        return *cached;
+    }
+
+    // This seems like a not great way to do this
+    // I should probably have written this function
+    // like push_box
+
+    match direction {
+        Direction::West => match map[Point::new(at.x - 1, at.y)] {
+            '.' => {
+                ok_cache.insert(at, true);
+                true
+            }
+            '#' => {
+                ok_cache.insert(at, false);
+                false
+            }
+            ']' => {
+                let result = check_box(Point::new(at.x - 2, at.y), direction, map, ok_cache);
+                ok_cache.insert(at, result);
+                result
+            }
+            _ => unreachable!(),
+        },
+        Direction::East => match map[Point::new(at.x + 2, at.y)] {
+            '.' => {
+                ok_cache.insert(at, true);
+                true
+            }
+            '#' => {
+                ok_cache.insert(at, false);
+                false
+            }
+            '[' => {
+                let result = check_box(Point::new(at.x + 2, at.y), direction, map, ok_cache);
+                ok_cache.insert(at, result);
+                result
+            }
+            _ => unreachable!(),
+        },
+        Direction::North | Direction::South => {
+            let ahead = at + direction.vector();
+            let l_val = map[ahead];
+            let r_val = map[Point::new(ahead.x + 1, ahead.y)];
+
+            match l_val {
+                '[' => {
+                    // Exactlty one box on top
+                    let result = check_box(ahead, direction, map, ok_cache);
+                    ok_cache.insert(at, result);
+                    return result;
+                }
+                ']' => {
+                    // Check this box, and if it can move, check the right side
+                    let result =
+                        check_box(Point::new(ahead.x - 1, ahead.y), direction, map, ok_cache);
+                    if !result {
+                        ok_cache.insert(at, result);
+                        return result;
+                    }
+                }
+                '#' => {
+                    ok_cache.insert(at, false);
+                    return false;
+                }
+                '.' => (), // Check right side
+                _ => unreachable!(),
+            }
+
+            match r_val {
+                '[' => {
+                    let result =
+                        check_box(Point::new(ahead.x + 1, ahead.y), direction, map, ok_cache);
+                    ok_cache.insert(at, result);
+                    result
+                }
+                '#' => {
+                    ok_cache.insert(at, false);
+                    false
+                }
+                '.' => {
+                    ok_cache.insert(at, true);
+                    true
+                }
+                _ => unreachable!("Found {l_val} {r_val}"),
+            }
+        }
+    }
+}
+
+impl Robot {
+    fn exec_narrow_move(&mut self, direction: Direction, map: &mut Grid<char>) {
+        let step = direction.vector();
+        let next = self.pos + step;
+        let mut end = next;
+        while map[end] == 'O' {
+            end = end + step;
+        }
+        match map[end] {
+            '.' => (),
+            '#' => return,
+            _ => unreachable!(),
+        }
+        if end != next {
+            map[end] = 'O';
+        }
+        map[self.pos] = '.';
+        self.pos = next;
+        map[next] = '@';
+    }
+
+    fn exec_move(&mut self, direction: Direction, map: &mut Grid<char>) {
+        let next = self.pos + direction.vector();
+        match map[next] {
+            '.' => (),
+            '#' => return,
+            '[' => {
+                if check_box(next, direction, map, &mut HashMap::new()) {
+                    push_box(next, direction, map);
+                } else {
+                    return;
+                }
+            }
+            ']' => {
+                let left = Point::new(next.x - 1, next.y);
+                if check_box(left, direction, map, &mut HashMap::new()) {
+                    push_box(left, direction, map);
+                } else {
+                    return;
+                }
+            }
+            _ => unreachable!(),
+        }
+        map[self.pos] = '.';
+        self.pos = next;
+        map[next] = '@';
+    }
+}
+
+type Warehouse = (Grid<char>, Vec<Direction>, Robot);
+
+fn parse(input: &str, widen: bool) -> Result<Warehouse, SolveError> {
+    let mut map: Vec<Vec<char>> = Vec::new();
+    let mut movement: Vec<Direction> = Vec::new();
+    let mut maybe_robot: Option<Robot> = None;
+    let mut parsing_map = true;
+    for (y, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            parsing_map = false;
+            continue;
+        }
+        if parsing_map {
+            let mut row: Vec<char> = Vec::new();
+            for (i, c) in line.char_indices() {
+                let tiles = match c {
+                    '#' => ['#', '#'],
+                    'O' if widen => ['[', ']'],
+                    'O' => ['O', '.'],
+                    '.' => ['.', '.'],
+                    '@' => ['@', '.'],
+                    _ => {
+                        return Err(SolveError::at_token(
+                            input,
+                            &line[i..i + c.len_utf8()],
+                            format!("unknown warehouse tile {c:?}"),
+                        ))
+                    }
+                };
+                if c == '@'
+                    && maybe_robot
+                        .replace(Robot {
+                            pos: Point::new(row.len(), y),
+                        })
+                        .is_some()
+                {
+                    return Err(SolveError::at_token(
+                        input,
+                        &line[i..i + c.len_utf8()],
+                        "found more than one robot",
+                    ));
+                }
+                row.extend(if widen { &tiles[..] } else { &tiles[..1] });
+            }
+            let is_wall = |c: Option<&char>| c == Some(&'#');
+            if !is_wall(row.first())
+                || !is_wall(row.last())
+                || map.first().is_some_and(|first| first.len() != row.len())
+            {
+                return Err(SolveError::at_token(
+                    input,
+                    line,
+                    "warehouse rows must be the same width and enclosed by walls",
+                ));
+            }
+            map.push(row);
+        } else {
+            for (i, c) in line.char_indices() {
+                movement.push(Direction::from_char(c).ok_or_else(|| {
+                    SolveError::at_token(
+                        input,
+                        &line[i..i + c.len_utf8()],
+                        format!("unknown move {c:?}"),
+                    )
+                })?);
+            }
+        }
+    }
+
+    let is_wall_row =
+        |row: Option<&Vec<char>>| row.is_some_and(|row| row.iter().all(|c| *c == '#'));
+    if !is_wall_row(map.first()) || !is_wall_row(map.last()) {
+        return Err(SolveError::new("warehouse must be enclosed by walls"));
+    }
+
+    let robot = maybe_robot.ok_or_else(|| SolveError::new("could not find the robot ('@')"))?;
+
+    Ok((Grid::from_rows(map)?, movement, robot))
+}
+
+fn gps_score(map: &Grid<char>, box_char: char) -> usize {
+    map.find_all(&box_char).map(|pos| 100 * pos.y + pos.x).sum()
+}
+
+pub fn part1(input: &str) -> Result<usize, SolveError> {
+    let (mut map, movement, mut robot) = parse(input, false)?;
+
+    for dir in movement {
+        robot.exec_narrow_move(dir, &mut map);
+    }
+
+    Ok(gps_score(&map, 'O'))
+}
+
+pub fn part2(input: &str) -> Result<usize, SolveError> {
+    let (mut map, movement, mut robot) = parse(input, true)?;
+
+    visualize::frame(|| Frame::from_rows(map.rows().map(|row| row.iter().copied())));
+    for dir in movement {
+        robot.exec_move(dir, &mut map);
+        visualize::frame(|| Frame::from_rows(map.rows().map(|row| row.iter().copied())));
+    }
+
+    Ok(gps_score(&map, '['))
+}
+
+impl crate::Solver for Solver {
+    fn solve_part1(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn solve_part2(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part2(input)?.to_string())
+    }
+}
@@ -0,0 +1,136 @@
+use std::collections::HashSet;
+
+use crate::{
+    geometry::{Direction, Point},
+    grid::Grid,
+    pathfinding::{self, Graph, ShortestPaths},
+    solvers::Registration,
+    visualize::{self, Frame},
+    Params, SolveError,
+};
+
+pub struct Solver {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2024,
+    day: 16,
+    title: "Reindeer Maze",
+    parts: &[1, 2],
+    solver: || Box::new(Solver {}),
+};
+
+type State = (Point, Direction);
+
+struct Maze {
+    map: Grid<char>,
+    start: Point,
+    end: Point,
+}
+
+impl Graph for Maze {
+    type Node = State;
+
+    // Stepping forward costs 1 and turning on the spot costs 1000
+    fn neighbors(&self, &(pos, direction): &State) -> Vec<(State, usize)> {
+        let mut moves = Vec::from([
+            ((pos, direction.rotate_cw()), 1000),
+            ((pos, direction.rotate_ccw()), 1000),
+        ]);
+        if let Some(next) = self
+            .map
+            .step(pos, direction)
+            .filter(|next| self.map[*next] != '#')
+        {
+            moves.push(((next, direction), 1));
+        }
+        moves
+    }
+}
+
+impl Maze {
+    fn parse(input: &str) -> Result<Maze, SolveError> {
+        let map = Grid::parse(input)?;
+        for (line, row) in input.lines().zip(map.rows()) {
+            if row.first() != Some(&'#') || row.last() != Some(&'#') {
+                return Err(SolveError::at_token(
+                    input,
+                    line,
+                    "maze rows must be enclosed by walls",
+                ));
+            }
+        }
+        if [map.row(0), map.row(map.height() - 1)]
+            .iter()
+            .any(|row| row.iter().any(|c| *c != '#'))
+        {
+            return Err(SolveError::new("maze must be enclosed by walls"));
+        }
+        let start = map
+            .find(&'S')
+            .ok_or_else(|| SolveError::new("could not find the start ('S')"))?;
+        let end = map
+            .find(&'E')
+            .ok_or_else(|| SolveError::new("could not find the end ('E')"))?;
+        Ok(Maze { map, start, end })
+    }
+
+    // Returns the lowest score together with every state the reindeer can end in with it
+    fn explore(&self) -> Result<(ShortestPaths<State>, usize, Vec<State>), SolveError> {
+        let paths = pathfinding::dijkstra(self, [(self.start, Direction::East)], |(pos, _)| {
+            *pos == self.end
+        });
+        let ends: Vec<(State, usize)> = Direction::ALL
+            .into_iter()
+            .filter_map(|direction| {
+                let state = (self.end, direction);
+                Some((state, paths.distance(&state)?))
+            })
+            .collect();
+        let best_val = ends
+            .iter()
+            .map(|(_, cost)| *cost)
+            .min()
+            .ok_or_else(|| SolveError::new("the end is unreachable"))?;
+        let best_ends = ends
+            .into_iter()
+            .filter(|(_, cost)| *cost == best_val)
+            .map(|(state, _)| state)
+            .collect();
+        Ok((paths, best_val, best_ends))
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, SolveError> {
+    let (_, best_val, _) = Maze::parse(input)?.explore()?;
+    Ok(best_val)
+}
+
+pub fn part2(input: &str) -> Result<usize, SolveError> {
+    let maze = Maze::parse(input)?;
+    let (paths, _, best_ends) = maze.explore()?;
+    let on_path: HashSet<Point> = paths
+        .on_shortest_paths(best_ends)
+        .into_iter()
+        .map(|(pos, _)| pos)
+        .collect();
+
+    visualize::frame(|| {
+        let mut frame = Frame::from_rows(maze.map.rows().map(|row| row.iter().copied()));
+        for &pos in &on_path {
+            frame.set(pos, 'O');
+        }
+        frame
+    });
+
+    Ok(on_path.len())
+}
+
+impl crate::Solver for Solver {
+    fn solve_part1(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn solve_part2(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part2(input)?.to_string())
+    }
+}
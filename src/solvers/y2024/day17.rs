@@ -0,0 +1,215 @@
+use crate::{
+    disassemble::{self, LoopShape},
+    solvers::Registration,
+    vm::{Registers, Step, Vm},
+    Param, Params, SolveError,
+};
+
+pub struct Solver {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2024,
+    day: 17,
+    title: "Chronospatial Computer",
+    parts: &[1, 2],
+    solver: || Box::new(Solver {}),
+};
+
+// Puzzle inputs halt within a few hundred steps, so a program still running after this many never will
+const RUN_STEP_LIMIT: usize = 10_000_000;
+
+// Every candidate in part 2 runs the whole program, which for puzzle inputs is a few hundred steps
+const TRIAL_STEP_LIMIT: usize = 100_000;
+
+// Every candidate tries `1 << shift` values for the bits shifted out of register A
+const MAX_SEARCH_SHIFT: u32 = 16;
+
+// Whether the program outputs exactly `target` with `a` in register A, stopping at the first
+// wrong value. A run that hasn't halted after `TRIAL_STEP_LIMIT` steps counts as a mismatch
+fn outputs(vm: &Vm, a: usize, target: &[u8]) -> Result<bool, SolveError> {
+    let mut trial = vm.clone();
+    trial.reset(Registers { a, ..vm.registers });
+    let mut matched: usize = 0;
+    loop {
+        if trial.steps() >= TRIAL_STEP_LIMIT {
+            return Ok(false);
+        }
+        match trial.step()? {
+            Step::Output(value) => {
+                if target.get(matched) != Some(&value) {
+                    return Ok(false);
+                }
+                matched += 1;
+            }
+            Step::Halted => return Ok(matched == target.len()),
+            Step::Executed(_) => (),
+        }
+    }
+}
+
+pub fn part1(input: &str) -> Result<Vec<u8>, SolveError> {
+    let mut vm = Vm::parse(input)?;
+    Ok(vm.run_with_limit(RUN_STEP_LIMIT)?.to_vec())
+}
+
+// Builds register A from its highest bits down, adding the bits that each iteration shifts out
+// once the values after them are known to be right
+fn search_by_shape(vm: &Vm, shape: LoopShape) -> Result<usize, SolveError> {
+    let program = vm.program();
+    if !program.len().is_multiple_of(shape.outputs) {
+        return Err(SolveError::new(format!(
+            "the program has {} values, which can't come from {} outputs per iteration",
+            program.len(),
+            shape.outputs
+        )));
+    }
+    if shape.shift > MAX_SEARCH_SHIFT {
+        return Err(SolveError::new(format!(
+            "the loop shifts a by {} bits, more than the {MAX_SEARCH_SHIFT} that can be searched",
+            shape.shift
+        )));
+    }
+    let mut candidates: Vec<usize> = Vec::from([0]);
+    for offset in (shape.outputs..=program.len()).step_by(shape.outputs) {
+        let mut new_candidates: Vec<usize> = Vec::new();
+        for candidate in &candidates {
+            if candidate.leading_zeros() < shape.shift {
+                return Err(SolveError::new("register A would need more than 64 bits"));
+            }
+            for low_bits in 0..(1 << shape.shift) {
+                let reg_a = (candidate << shape.shift) + low_bits;
+                if outputs(vm, reg_a, &program[(program.len() - offset)..])? {
+                    new_candidates.push(reg_a);
+                }
+            }
+        }
+        candidates = new_candidates;
+    }
+
+    candidates
+        .iter()
+        .min()
+        .copied()
+        .ok_or_else(|| SolveError::new("no value of register A makes the program output itself"))
+}
+
+// Programs `loop_shape` can't describe are brute forced, trying register A values one at a time
+pub fn part2(input: &str, brute_force_limit: usize) -> Result<usize, SolveError> {
+    let vm = Vm::parse(input)?;
+    // `disassemble <input>` shows what one iteration of the loop outputs
+    let shape_err = match disassemble::loop_shape(vm.program()) {
+        Ok(shape) => return search_by_shape(&vm, shape),
+        Err(shape_err) => shape_err,
+    };
+    for reg_a in 0..brute_force_limit {
+        if outputs(&vm, reg_a, vm.program())? {
+            return Ok(reg_a);
+        }
+    }
+    Err(SolveError::new(format!(
+        "{}, so register A was brute forced up to {brute_force_limit} and no value makes the \
+         program output itself",
+        shape_err.message
+    )))
+}
+
+const PARAMS: [Param; 1] = [Param {
+    name: "brute_force_limit",
+    default: 1 << 20,
+    description: "Register A values brute forced in part 2 when the program is not a simple loop",
+}];
+
+impl crate::Solver for Solver {
+    fn params(&self) -> &'static [Param] {
+        &PARAMS
+    }
+
+    fn solve_part1(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        let output: Vec<String> = part1(input)?.iter().map(|out| out.to_string()).collect();
+        Ok(output.join(","))
+    }
+
+    fn solve_part2(&self, input: &str, params: &Params) -> Result<String, SolveError> {
+        Ok(part2(input, params.get("brute_force_limit")?)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(b: usize, program: &[u8]) -> String {
+        let program: Vec<String> = program.iter().map(u8::to_string).collect();
+        format!(
+            "Register A: 0\nRegister B: {b}\nRegister C: 0\n\nProgram: {}",
+            program.join(",")
+        )
+    }
+
+    #[test]
+    fn part1_stops_programs_that_never_halt() {
+        let err = part1("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,0").unwrap_err();
+        assert_eq!(
+            err.message,
+            format!("program did not halt within {RUN_STEP_LIMIT} steps")
+        );
+    }
+
+    #[test]
+    fn search_by_shape_with_a_shift_of_3() {
+        assert_eq!(part2(&input(0, &[0, 3, 5, 4, 3, 0]), 0).unwrap(), 117440);
+    }
+
+    #[test]
+    fn search_by_shape_with_two_outputs_per_shift_of_6() {
+        // out a, b = a >> 3, out b, a = a >> 6, which outputs the octal digits of A from the lowest
+        let program = [5, 4, 6, 3, 5, 5, 0, 3, 0, 3, 3, 0];
+        let shape = disassemble::loop_shape(&program).unwrap();
+        assert_eq!(
+            shape,
+            LoopShape {
+                shift: 6,
+                outputs: 2
+            }
+        );
+        let expected = program
+            .iter()
+            .rev()
+            .fold(0, |acc, &digit| acc * 8 + digit as usize);
+        assert_eq!(part2(&input(0, &program), 0).unwrap(), expected);
+    }
+
+    #[test]
+    fn search_by_shape_without_a_quine() {
+        // out a % 8 while shifting by 2, where neighbouring outputs share a bit they disagree on
+        let program = [2, 4, 5, 5, 0, 2, 3, 0];
+        assert_eq!(
+            part2(&input(0, &program), 0).unwrap_err().message,
+            "no value of register A makes the program output itself"
+        );
+    }
+
+    #[test]
+    fn search_by_shape_limits_the_shift() {
+        let program = [5, 4, 0, 3, 0, 3, 0, 3, 0, 3, 0, 3, 0, 3, 3, 0];
+        assert_eq!(
+            part2(&input(0, &program), 0).unwrap_err().message,
+            "the loop shifts a by 18 bits, more than the 16 that can be searched"
+        );
+    }
+
+    #[test]
+    fn other_programs_are_brute_forced() {
+        // Outputs the B left by the previous iteration, so the first output is B's starting value
+        let program = [5, 5, 2, 4, 0, 3, 3, 0];
+        let err = part2(&input(5, &program), 1000).unwrap_err();
+        assert_eq!(
+            err.message,
+            "the outputs depend on register B from the previous iteration, so register A was \
+             brute forced up to 1000 and no value makes the program output itself"
+        );
+        // The octal digits of A are the outputs after the first, plus a 1 to keep the loop going
+        let expected = 0o10330425;
+        assert_eq!(part2(&input(5, &program), expected + 1).unwrap(), expected);
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{
+    geometry::Point,
+    grid::Grid,
+    parse_token,
+    pathfinding::{self, Graph},
+    solvers::Registration,
+    Param, Params, SolveError,
+};
+
+pub struct Solver {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2024,
+    day: 18,
+    title: "RAM Run",
+    parts: &[1, 2],
+    solver: || Box::new(Solver {}),
+};
+
+type Board = Grid<bool>;
+
+fn parse_bytes(input: &str, board_size: usize) -> Result<Vec<Point>, SolveError> {
+    if board_size == 0 {
+        return Err(SolveError::new("the memory space must not be empty"));
+    }
+    input
+        .lines()
+        .map(|line| {
+            let Some((x, y)) = line.split_once(",") else {
+                return Err(SolveError::at_token(
+                    input,
+                    line,
+                    "expected a byte like 'X,Y'",
+                ));
+            };
+            let x: usize = parse_token(input, x)?;
+            let y: usize = parse_token(input, y)?;
+            if x >= board_size || y >= board_size {
+                return Err(SolveError::at_token(
+                    input,
+                    line,
+                    format!("byte falls outside the {board_size}x{board_size} memory space"),
+                ));
+            }
+            Ok(Point::new(x, y))
+        })
+        .collect()
+}
+
+impl Graph for Board {
+    type Node = Point;
+
+    fn neighbors(&self, pos: &Point) -> Vec<(Point, usize)> {
+        self.neighbors4(*pos)
+            .filter(|next| !self[*next])
+            .map(|next| (next, 1))
+            .collect()
+    }
+}
+
+fn exit(blocked: &Board) -> Point {
+    Point::new(blocked.width() - 1, blocked.height() - 1)
+}
+
+pub fn part1(input: &str, board_size: usize, first_fallen: usize) -> Result<usize, SolveError> {
+    let mut blocked: Board = Grid::new(board_size, board_size, false);
+    for byte in parse_bytes(input, board_size)?
+        .into_iter()
+        .take(first_fallen)
+    {
+        blocked[byte] = true;
+    }
+    let exit = exit(&blocked);
+    let (steps, _) = pathfinding::astar(
+        &blocked,
+        Point::new(0, 0),
+        |pos| *pos == exit,
+        |pos| pos.manhattan(exit),
+    )
+    .ok_or_else(|| SolveError::new("the exit is unreachable"))?;
+    Ok(steps)
+}
+
+pub fn part2(input: &str, board_size: usize) -> Result<Point, SolveError> {
+    let mut blocked: Board = Grid::new(board_size, board_size, false);
+    let bytes = parse_bytes(input, board_size)?;
+    let mut lo: usize = 0;
+    let mut hi: usize = bytes.len();
+    let mut cursor: usize = 0;
+    let exit = exit(&blocked);
+
+    if let Some(first) = bytes.first() {
+        blocked[*first] = true;
+    }
+
+    while lo < hi {
+        let target = (lo + hi) / 2;
+        while cursor < target {
+            cursor += 1;
+            blocked[bytes[cursor]] = true;
+        }
+
+        while cursor > target {
+            blocked[bytes[cursor]] = false;
+            cursor -= 1;
+        }
+
+        let paths = pathfinding::bfs(&blocked, [Point::new(0, 0)], |pos| *pos == exit);
+        match paths.distance(&exit) {
+            Some(_) => lo = target + 1,
+            None => hi = target,
+        }
+    }
+
+    bytes
+        .get(lo)
+        .copied()
+        .ok_or_else(|| SolveError::new("the exit never becomes unreachable"))
+}
+
+const PARAMS: [Param; 2] = [
+    Param {
+        name: "board_size",
+        default: 71,
+        description: "Width and height of the memory space",
+    },
+    Param {
+        name: "first_fallen",
+        default: 1024,
+        description: "Number of bytes that have fallen in part 1",
+    },
+];
+
+impl crate::Solver for Solver {
+    fn params(&self) -> &'static [Param] {
+        &PARAMS
+    }
+
+    fn solve_part1(&self, input: &str, params: &Params) -> Result<String, SolveError> {
+        let answer = part1(
+            input,
+            params.get("board_size")?,
+            params.get("first_fallen")?,
+        )?;
+        Ok(answer.to_string())
+    }
+
+    fn solve_part2(&self, input: &str, params: &Params) -> Result<String, SolveError> {
+        Ok(part2(input, params.get("board_size")?)?.to_string())
+    }
+}
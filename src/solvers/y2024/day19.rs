@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+
+use crate::{solvers::Registration, Params, SolveError};
+
+pub struct Solver {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2024,
+    day: 19,
+    title: "Linen Layout",
+    parts: &[1, 2],
+    solver: || Box::new(Solver {}),
+};
+
+fn is_formable(patterns: &[String], pattern: &str, cache: &mut HashMap<String, usize>) -> usize {
+    if pattern.is_empty() {
+        return 1;
+    }
+    if let Some(cached) = cache.get(pattern) {
+        return *cached;
+    }
+    let mut formable: usize = 0;
+    for p in patterns {
+        if let Some(rest) = pattern.strip_prefix(p.as_str()) {
+            formable += is_formable(patterns, rest, cache)
+        }
+    }
+    cache.insert(pattern.to_string(), formable);
+    formable
+}
+
+fn count_arrangements(input: &str) -> Vec<usize> {
+    let mut patterns: Vec<String> = Vec::new();
+    let mut arrangements: Vec<usize> = Vec::new();
+
+    for line in input.lines() {
+        if patterns.is_empty() {
+            patterns.extend(line.split(", ").map(|pattern| pattern.to_string()));
+        } else if !line.is_empty() {
+            arrangements.push(is_formable(&patterns, line, &mut HashMap::new()));
+        }
+    }
+
+    arrangements
+}
+
+pub fn part1(input: &str) -> Result<usize, SolveError> {
+    Ok(count_arrangements(input)
+        .iter()
+        .filter(|ways| **ways > 0)
+        .count())
+}
+
+pub fn part2(input: &str) -> Result<usize, SolveError> {
+    Ok(count_arrangements(input).iter().sum::<usize>())
+}
+
+impl crate::Solver for Solver {
+    fn solve_part1(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn solve_part2(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part2(input)?.to_string())
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{parse, solvers::Registration, Params, SolveError};
+
+pub struct Solver {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2024,
+    day: 2,
+    title: "Red-Nosed Reports",
+    parts: &[1, 2],
+    solver: || Box::new(Solver {}),
+};
+
+fn parse_reports(input: &str) -> Result<Vec<Vec<usize>>, SolveError> {
+    input
+        .lines()
+        .map(|report| parse::list(input, report, " "))
+        .collect()
+}
+
+fn is_safe(levels: impl Iterator<Item = usize>) -> bool {
+    let mut prev: Option<usize> = None;
+    let mut increasing = false;
+    let mut decreasing = false;
+    for level in levels {
+        if let Some(prev) = prev {
+            if prev > level {
+                decreasing = true;
+            } else if prev < level {
+                increasing = true;
+            }
+            let diff = prev.abs_diff(level);
+            if !(1..=3).contains(&diff) {
+                return false;
+            }
+            if increasing && decreasing {
+                return false;
+            }
+        }
+        prev = Some(level);
+    }
+    true
+}
+
+pub fn part1(input: &str) -> Result<usize, SolveError> {
+    Ok(parse_reports(input)?
+        .iter()
+        .filter(|levels| is_safe(levels.iter().copied()))
+        .count())
+}
+
+pub fn part2(input: &str) -> Result<usize, SolveError> {
+    let mut num_safe: usize = 0;
+    for levels in parse_reports(input)? {
+        for skip in 0..levels.len() {
+            if is_safe(
+                levels
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| *i != skip)
+                    .map(|(_, v)| *v),
+            ) {
+                num_safe += 1;
+                break;
+            }
+        }
+    }
+    Ok(num_safe)
+}
+
+impl crate::Solver for Solver {
+    fn solve_part1(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn solve_part2(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part2(input)?.to_string())
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{
+    geometry::{Point, Vector},
+    grid::Grid,
+    pathfinding::{self, Graph},
+    solvers::Registration,
+    Param, Params, SolveError,
+};
+
+pub struct Solver {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2024,
+    day: 20,
+    title: "Race Condition",
+    parts: &[1, 2],
+    solver: || Box::new(Solver {}),
+};
+
+struct Track(Grid<char>);
+
+impl Graph for Track {
+    type Node = Point;
+
+    fn neighbors(&self, pos: &Point) -> Vec<(Point, usize)> {
+        self.0
+            .neighbors4(*pos)
+            .filter(|next| self.0[*next] != '#')
+            .map(|next| (next, 1))
+            .collect()
+    }
+}
+
+fn count_cheats(input: &str, max_cheat: isize, min_savings: usize) -> Result<usize, SolveError> {
+    let track = Track(Grid::parse(input)?);
+    let exit = track
+        .0
+        .find(&'E')
+        .ok_or_else(|| SolveError::new("could not find the end ('E')"))?;
+    track
+        .0
+        .find(&'S')
+        .ok_or_else(|| SolveError::new("could not find the start ('S')"))?;
+
+    // Every cheat is scored by the distance to the exit on either end, so flood the whole track
+    let paths = pathfinding::bfs(&track, [exit], |_| false);
+    let bests: Grid<Option<usize>> = {
+        let mut bests = track.0.map(|_| None);
+        for (pos, distance) in paths.distances() {
+            bests[*pos] = Some(*distance);
+        }
+        bests
+    };
+
+    let mut decent_cheats: usize = 0;
+
+    for (pos, best) in bests.iter() {
+        let Some(from_best) = *best else {
+            continue;
+        };
+
+        for dx in -max_cheat..=max_cheat {
+            for dy in -(max_cheat - dx.abs())..=(max_cheat - dx.abs()) {
+                let cheat = Vector::new(dx, dy);
+                let Some(to_best) = bests.offset(pos, cheat).and_then(|next| bests[next]) else {
+                    continue;
+                };
+                if let Some(savings) = from_best.checked_sub(to_best + cheat.manhattan()) {
+                    if savings >= min_savings {
+                        decent_cheats += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(decent_cheats)
+}
+
+pub fn part1(input: &str, max_cheat: isize, min_savings: usize) -> Result<usize, SolveError> {
+    count_cheats(input, max_cheat, min_savings)
+}
+
+pub fn part2(input: &str, max_cheat: isize, min_savings: usize) -> Result<usize, SolveError> {
+    count_cheats(input, max_cheat, min_savings)
+}
+
+const PARAMS: [Param; 3] = [
+    Param {
+        name: "min_savings",
+        default: 100,
+        description: "Picoseconds a cheat must save to be counted",
+    },
+    Param {
+        name: "part1_cheat",
+        default: 2,
+        description: "Longest cheat in picoseconds in part 1",
+    },
+    Param {
+        name: "part2_cheat",
+        default: 20,
+        description: "Longest cheat in picoseconds in part 2",
+    },
+];
+
+impl crate::Solver for Solver {
+    fn params(&self) -> &'static [Param] {
+        &PARAMS
+    }
+
+    fn solve_part1(&self, input: &str, params: &Params) -> Result<String, SolveError> {
+        let answer = part1(
+            input,
+            params.get("part1_cheat")?,
+            params.get("min_savings")?,
+        )?;
+        Ok(answer.to_string())
+    }
+
+    fn solve_part2(&self, input: &str, params: &Params) -> Result<String, SolveError> {
+        let answer = part2(
+            input,
+            params.get("part2_cheat")?,
+            params.get("min_savings")?,
+        )?;
+        Ok(answer.to_string())
+    }
+}
@@ -0,0 +1,294 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    geometry::{Direction, Point},
+    parse_token,
+    solvers::Registration,
+    Param, Params, SolveError,
+};
+
+pub struct Solver {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2024,
+    day: 21,
+    title: "Keypad Conundrum",
+    parts: &[1, 2],
+    solver: || Box::new(Solver {}),
+};
+
+fn is_optimal(path: &[Point]) -> bool {
+    if path.is_empty() {
+        return true;
+    }
+
+    // Assume that duplicate points are not present
+
+    let first = path.first().unwrap();
+    let last = path.last().unwrap();
+    path.len() - 1 == first.manhattan(*last)
+}
+
+type CoordMap = HashMap<char, Point>;
+type PathMap = HashMap<(char, char), HashSet<Vec<char>>>;
+
+fn cache_paths<const A: usize, const B: usize>(
+    map: &[[Option<char>; A]; B],
+    coord_map: &mut CoordMap,
+    paths: &mut PathMap,
+) {
+    for (y, row) in map.iter().enumerate() {
+        for (x, c) in row.iter().enumerate() {
+            let Some(start) = *c else {
+                continue;
+            };
+            paths.insert((start, start), HashSet::from([Vec::new()]));
+
+            let start_pos = Point::new(x, y);
+            assert!(coord_map.insert(start, start_pos).is_none());
+            let mut explore_list: HashSet<Vec<Point>> = HashSet::from([Vec::from([start_pos])]);
+            while !explore_list.is_empty() {
+                explore_list = HashSet::from_iter(
+                    explore_list
+                        .iter()
+                        .flat_map(|path| {
+                            let last = *path.last().unwrap();
+                            Direction::ALL.iter().filter_map(move |direction| {
+                                let mut extended = path.clone();
+                                extended.push(last.step(*direction)?);
+                                Some(extended)
+                            })
+                        })
+                        .filter(|path| {
+                            let last = path.last().unwrap();
+                            map.get(last.y)
+                                .is_some_and(|row| row.get(last.x).copied().flatten().is_some())
+                                && is_optimal(path)
+                        }),
+                );
+
+                for list in &explore_list {
+                    let last = list.last().unwrap();
+                    let end = map[last.y][last.x].unwrap();
+                    paths.entry((start, end)).or_default().insert(
+                        list.windows(2)
+                            .map(|window| {
+                                Direction::from_vector(window[1] - window[0])
+                                    .unwrap()
+                                    .to_char()
+                            })
+                            .collect(),
+                    );
+                }
+            }
+        }
+    }
+}
+
+type SolveCache = HashMap<Vec<char>, HashMap<char, HashSet<Vec<char>>>>;
+
+fn solve(
+    init: char,
+    seq: &[char],
+    path_map: &PathMap,
+    cache: &mut SolveCache,
+) -> HashSet<Vec<char>> {
+    if let Some(cached) = cache.get(&seq.to_vec()).and_then(|cmap| cmap.get(&init)) {
+        return cached.clone();
+    }
+
+    let paths: HashSet<Vec<char>> = HashSet::from([Vec::new()]);
+    let prev = init;
+
+    if seq.len() == 1 {
+        let next = seq[0];
+        if prev != next {
+            let extensions = path_map.get(&(prev, next)).unwrap();
+            HashSet::from_iter(paths.iter().flat_map(|path| {
+                extensions.iter().map(|ext| {
+                    let mut extended = path.clone();
+                    extended.extend(ext);
+                    extended.push('A');
+                    extended
+                })
+            }))
+        } else {
+            HashSet::from_iter(paths.iter().map(|path| {
+                let mut extended = path.clone();
+                extended.push('A');
+                extended
+            }))
+        }
+    } else {
+        let split = seq.len() / 2;
+        let split_init = seq[split - 1];
+        let second_half = solve(split_init, &seq[split..], path_map, cache);
+        let result =
+            HashSet::from_iter(solve(init, &seq[..split], path_map, cache).iter().flat_map(
+                |first_half| {
+                    second_half.iter().map(|second| {
+                        let mut first = first_half.clone();
+                        first.extend(second.iter());
+                        first
+                    })
+                },
+            ));
+
+        if let Some(init_map) = cache.get_mut(seq) {
+            init_map.insert(init, result.clone());
+        } else {
+            let mut init_map = HashMap::new();
+            init_map.insert(init, result.clone());
+            cache.insert(seq.to_vec(), init_map);
+        }
+
+        result
+    }
+}
+
+fn path_to_transitions(path: &[char]) -> HashMap<(char, char), usize> {
+    let mut result: HashMap<(char, char), usize> = HashMap::new();
+    let mut prev = 'A';
+    for c in path {
+        let next = *c;
+        result.insert((prev, next), result.get(&(prev, next)).unwrap_or(&0) + 1);
+        prev = next;
+    }
+    result.insert((prev, 'A'), result.get(&(prev, 'A')).unwrap_or(&0) + 1);
+    result
+}
+
+type TransitionCache = HashMap<(char, char, usize), usize>;
+
+fn solve_transition(
+    transition: (char, char),
+    depth: usize,
+    path_map: &PathMap,
+    cache: &mut TransitionCache,
+) -> usize {
+    if let Some(cached) = cache.get(&(transition.0, transition.1, depth)) {
+        return *cached;
+    }
+
+    if depth == 0 {
+        // Manual input
+        return 1;
+    }
+
+    let result = path_map
+        .get(&transition)
+        .unwrap()
+        .iter()
+        .map(|path| {
+            let transitions = path_to_transitions(path);
+            let mut num_transitions: usize = 0;
+            for (transition, count) in transitions {
+                num_transitions += count * solve_transition(transition, depth - 1, path_map, cache);
+            }
+            num_transitions
+        })
+        .min()
+        .unwrap();
+
+    cache.insert((transition.0, transition.1, depth), result);
+    result
+}
+
+fn total_complexity(input: &str, depth: usize) -> Result<usize, SolveError> {
+    let numeric: [[Option<char>; 3]; 4] = [
+        [Some('7'), Some('8'), Some('9')],
+        [Some('4'), Some('5'), Some('6')],
+        [Some('1'), Some('2'), Some('3')],
+        [None, Some('0'), Some('A')],
+    ];
+
+    let mut numeric_paths: PathMap = HashMap::new();
+    let mut number_to_coord: CoordMap = HashMap::new();
+
+    cache_paths(&numeric, &mut number_to_coord, &mut numeric_paths);
+
+    let directional: [[Option<char>; 3]; 2] = [
+        [None, Some('^'), Some('A')],
+        [Some('<'), Some('v'), Some('>')],
+    ];
+    let mut direction_paths: PathMap = HashMap::new();
+    let mut direction_to_coord: CoordMap = HashMap::new();
+
+    cache_paths(&directional, &mut direction_to_coord, &mut direction_paths);
+
+    let mut transition_cache: TransitionCache = TransitionCache::new();
+    let mut acc: usize = 0;
+    for line in input.lines() {
+        let Some(code) = line
+            .strip_suffix('A')
+            .filter(|code| !code.is_empty() && code.chars().all(|c| c.is_ascii_digit()))
+        else {
+            return Err(SolveError::at_token(
+                input,
+                line,
+                "expected a door code like '029A'",
+            ));
+        };
+        let chars: Vec<char> = line.chars().collect();
+        let paths = solve('A', &chars[..], &numeric_paths, &mut HashMap::new());
+        let min_transitions_needed: usize = paths
+            .iter()
+            .map(|path| {
+                let transitions = path_to_transitions(path);
+                transitions
+                    .iter()
+                    .map(|(transition, count)| {
+                        count
+                            * solve_transition(
+                                *transition,
+                                depth,
+                                &direction_paths,
+                                &mut transition_cache,
+                            )
+                    })
+                    .sum::<usize>()
+            })
+            .min()
+            .unwrap();
+
+        let min_inputs = min_transitions_needed - 1; // Last transition (last -> A) is a dummy transition
+        acc += parse_token::<usize>(input, code)? * min_inputs;
+    }
+
+    Ok(acc)
+}
+
+pub fn part1(input: &str, depth: usize) -> Result<usize, SolveError> {
+    total_complexity(input, depth)
+}
+
+pub fn part2(input: &str, depth: usize) -> Result<usize, SolveError> {
+    total_complexity(input, depth)
+}
+
+const PARAMS: [Param; 2] = [
+    Param {
+        name: "part1_depth",
+        default: 2,
+        description: "Number of directional keypad robots in part 1",
+    },
+    Param {
+        name: "part2_depth",
+        default: 25,
+        description: "Number of directional keypad robots in part 2",
+    },
+];
+
+impl crate::Solver for Solver {
+    fn params(&self) -> &'static [Param] {
+        &PARAMS
+    }
+
+    fn solve_part1(&self, input: &str, params: &Params) -> Result<String, SolveError> {
+        Ok(part1(input, params.get("part1_depth")?)?.to_string())
+    }
+
+    fn solve_part2(&self, input: &str, params: &Params) -> Result<String, SolveError> {
+        Ok(part2(input, params.get("part2_depth")?)?.to_string())
+    }
+}
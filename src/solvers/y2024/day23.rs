@@ -0,0 +1,110 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::{solvers::Registration, Params, SolveError};
+
+pub struct Solver {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2024,
+    day: 23,
+    title: "LAN Party",
+    parts: &[1, 2],
+    solver: || Box::new(Solver {}),
+};
+
+type Connections = HashMap<String, BTreeSet<String>>;
+
+fn parse(input: &str) -> Result<(HashSet<String>, Connections), SolveError> {
+    let mut computers: HashSet<String> = HashSet::new();
+    let mut connections: Connections = HashMap::new();
+
+    for line in input.lines() {
+        let Some((comp_a, comp_b)) = line.split_once('-') else {
+            return Err(SolveError::at_token(
+                input,
+                line,
+                "expected a connection like 'ab-cd'",
+            ));
+        };
+        let comp_a = comp_a.to_string();
+        let comp_b = comp_b.to_string();
+        computers.insert(comp_a.clone());
+        computers.insert(comp_b.clone());
+
+        if let Some(conns_a) = connections.get_mut(&comp_a) {
+            conns_a.insert(comp_b.clone());
+        } else {
+            connections.insert(comp_a.clone(), BTreeSet::from([comp_b.clone()]));
+        }
+
+        if let Some(conns_b) = connections.get_mut(&comp_b) {
+            conns_b.insert(comp_a);
+        } else {
+            connections.insert(comp_b, BTreeSet::from([comp_a]));
+        }
+    }
+
+    Ok((computers, connections))
+}
+
+pub fn part1(input: &str) -> Result<usize, SolveError> {
+    let (_, connections) = parse(input)?;
+    let mut triangles: HashSet<BTreeSet<&String>> = HashSet::new();
+
+    for (comp_a, conns_a) in connections.iter().filter(|(comp, _)| comp.starts_with('t')) {
+        for comp_b in conns_a {
+            for comp_c in connections.get(comp_b).unwrap().intersection(conns_a) {
+                triangles.insert(BTreeSet::from([comp_a, comp_b, comp_c]));
+            }
+        }
+    }
+
+    Ok(triangles.len())
+}
+
+pub fn part2(input: &str) -> Result<Vec<String>, SolveError> {
+    let (computers, connections) = parse(input)?;
+
+    let mut cliques: BTreeSet<BTreeSet<String>> = BTreeSet::from_iter(
+        computers
+            .iter()
+            .map(|computer| BTreeSet::from_iter([computer.clone()])),
+    );
+    let mut seen_cliques: HashSet<BTreeSet<String>> = HashSet::new();
+    let mut maximal_cliques: HashSet<BTreeSet<String>> = HashSet::new();
+
+    // Fast enough clique finding algorithm
+    while let Some(clique) = cliques.pop_last() {
+        let mut found_any = false;
+        for comp in &computers {
+            let connected = connections.get(comp).unwrap();
+            if clique.is_subset(connected) && !clique.contains(comp) {
+                let mut expanded = clique.clone();
+                expanded.insert(comp.clone());
+                if !seen_cliques.contains(&expanded) {
+                    found_any = true;
+                    seen_cliques.insert(expanded.clone());
+                    cliques.insert(expanded);
+                }
+            }
+        }
+        if !found_any {
+            maximal_cliques.insert(clique);
+        }
+    }
+    let largest = maximal_cliques
+        .iter()
+        .max_by_key(|clique| clique.len())
+        .ok_or_else(|| SolveError::new("network has no computers"))?;
+    Ok(largest.iter().cloned().collect())
+}
+
+impl crate::Solver for Solver {
+    fn solve_part1(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn solve_part2(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part2(input)?.join(","))
+    }
+}
@@ -0,0 +1,893 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use crate::{
+    netlist::{self, GateKind, Netlist, NetlistBuilder},
+    parse_token,
+    solvers::Registration,
+    Params, SolveError,
+};
+
+pub struct Solver {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2024,
+    day: 24,
+    title: "Crossed Wires",
+    parts: &[1, 2],
+    solver: || Box::new(Solver {}),
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum OperationClass {
+    RawAdd,
+    RawCarry,
+    CombinedCarry,
+    FullCarry,
+    Result,
+    Error,
+}
+
+// What part of the adder a gate was recognised as
+#[derive(Clone, Copy, Debug, Default)]
+struct Label {
+    class: Option<OperationClass>,
+    target_bit: Option<usize>,
+}
+
+impl Label {
+    fn is_incomplete(&self) -> bool {
+        self.target_bit.is_none() && self.class.is_none_or(|c| c == OperationClass::Error)
+    }
+}
+
+// Gates are referred to by their index in the netlist, so a gate keeps its label when its output
+// is swapped
+struct OperationContext {
+    labels: Vec<Label>,
+    raw_add: HashMap<usize, usize>,        // xnn XOR ynn
+    raw_carry: HashMap<usize, usize>,      // xnn AND ynn
+    combined_carry: HashMap<usize, usize>, // full_carry AND raw_add
+    full_carry: HashMap<usize, usize>,     // raw_carry OR combined_carry
+    result: HashMap<usize, usize>,         // full_carry XOR raw_add
+    unused: HashSet<usize>,
+}
+
+impl OperationContext {
+    fn new(gate_count: usize) -> OperationContext {
+        OperationContext {
+            labels: vec![Label::default(); gate_count],
+            raw_add: HashMap::new(),
+            raw_carry: HashMap::new(),
+            combined_carry: HashMap::new(),
+            full_carry: HashMap::new(),
+            result: HashMap::new(),
+            // Every gate starts out unlabelled
+            unused: (0..gate_count).collect(),
+        }
+    }
+
+    fn get_map_for_gate_mut(&mut self, gate: usize) -> Option<&mut HashMap<usize, usize>> {
+        let label = self.labels[gate];
+        if label.is_incomplete() {
+            return None;
+        }
+        Some(match label.class.unwrap() {
+            OperationClass::RawAdd => &mut self.raw_add,
+            OperationClass::RawCarry => &mut self.raw_carry,
+            OperationClass::CombinedCarry => &mut self.combined_carry,
+            OperationClass::FullCarry => &mut self.full_carry,
+            OperationClass::Result => &mut self.result,
+            OperationClass::Error => unreachable!(),
+        })
+    }
+
+    fn track_gate(&mut self, gate: usize) -> Option<()> {
+        if self.labels[gate].is_incomplete() {
+            if !self.unused.insert(gate) {
+                return None;
+            }
+        } else {
+            let target_bit = self.labels[gate].target_bit.unwrap();
+            let map = self.get_map_for_gate_mut(gate).unwrap();
+            if map.insert(target_bit, gate).is_some() {
+                // Gate was already being tracked
+                return None;
+            }
+        }
+        Some(())
+    }
+
+    fn untrack_gate(&mut self, gate: usize) -> Option<()> {
+        if self.labels[gate].is_incomplete() {
+            if !self.unused.remove(&gate) {
+                return None;
+            }
+        } else {
+            let target_bit = self.labels[gate].target_bit.unwrap();
+            let map = self.get_map_for_gate_mut(gate).unwrap();
+            if map
+                .get(&target_bit)
+                .is_none_or(|&found_gate| found_gate != gate)
+            {
+                return None;
+            }
+            map.remove(&target_bit);
+        }
+        Some(())
+    }
+
+    fn make_complete(
+        &mut self,
+        gate: usize,
+        class: OperationClass,
+        target_bit: usize,
+    ) -> Result<(), SolveError> {
+        self.untrack_gate(gate)
+            .and_then(|_| {
+                self.labels[gate] = Label {
+                    class: Some(class),
+                    target_bit: Some(target_bit),
+                };
+                self.track_gate(gate)
+            })
+            .ok_or_else(|| {
+                not_an_adder(format!(
+                    "more than one gate looks like the {class:?} of bit {target_bit}"
+                ))
+            })
+    }
+
+    fn make_error(&mut self, gate: usize) -> Result<(), SolveError> {
+        self.untrack_gate(gate)
+            .and_then(|_| {
+                self.labels[gate] = Label {
+                    class: Some(OperationClass::Error),
+                    target_bit: None,
+                };
+                self.track_gate(gate)
+            })
+            .ok_or_else(|| not_an_adder("a gate was classified twice"))
+    }
+}
+
+fn not_an_adder(reason: impl Display) -> SolveError {
+    SolveError::new(format!(
+        "the circuit is not a ripple-carry adder with swapped outputs: {reason}"
+    ))
+}
+
+fn require(condition: bool, reason: impl FnOnce() -> String) -> Result<(), SolveError> {
+    if condition {
+        Ok(())
+    } else {
+        Err(not_an_adder(reason()))
+    }
+}
+
+enum OperationValidationError {
+    ClassMissing,
+    ClassIsError,
+    TargetBitMissing,
+    BadOpType,
+    BadInput(String),
+    BadOutput(String),
+}
+
+impl Display for OperationValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OperationValidationError::ClassMissing => write!(f, "Class has not been assigned."),
+            OperationValidationError::ClassIsError => write!(f, "Class is assigned as 'Error'."),
+            OperationValidationError::TargetBitMissing => {
+                write!(f, "Target bit has not been assigned.")
+            }
+            OperationValidationError::BadOpType => {
+                write!(f, "Operation type impossible for class.")
+            }
+            OperationValidationError::BadInput(wire) => {
+                write!(f, "Input wire '{}' is invalid.", wire)
+            }
+            OperationValidationError::BadOutput(wire) => {
+                write!(f, "Output wire should be '{}'.", wire)
+            }
+        }
+    }
+}
+
+fn logical_validate(
+    gate: usize,
+    netlist: &Netlist,
+    labels: &[Label],
+) -> Result<(), OperationValidationError> {
+    let label = labels[gate];
+    let op = netlist.gate(gate);
+    let [wire_a, wire_b] = op.inputs.map(|wire| netlist.name(wire).to_string());
+    if label.class.is_none() {
+        return Err(OperationValidationError::ClassMissing);
+    }
+    if label.target_bit.is_none() {
+        return Err(OperationValidationError::TargetBitMissing);
+    }
+    let target_bit = label.target_bit.unwrap();
+    match label.class.unwrap() {
+        OperationClass::RawAdd | OperationClass::RawCarry => {
+            let kind = if label.class == Some(OperationClass::RawAdd) {
+                GateKind::Xor
+            } else {
+                GateKind::And
+            };
+            if op.kind != kind {
+                return Err(OperationValidationError::BadOpType);
+            }
+            let x_target = format!("x{:02}", target_bit);
+            let y_target = format!("y{:02}", target_bit);
+            for (wire, name) in op.inputs.iter().zip([wire_a, wire_b]) {
+                if netlist.driver(*wire).is_some() {
+                    return Err(OperationValidationError::BadInput(name));
+                }
+                if name != x_target && name != y_target {
+                    return Err(OperationValidationError::BadInput(name));
+                }
+            }
+            Ok(())
+        }
+        OperationClass::CombinedCarry => {
+            if op.kind != GateKind::And {
+                return Err(OperationValidationError::BadOpType);
+            }
+            for (wire, name) in op.inputs.iter().zip([wire_a, wire_b]) {
+                let Some(input) = netlist.driver(*wire).map(|driver| labels[driver]) else {
+                    return Err(OperationValidationError::BadInput(name));
+                };
+                if input.target_bit.is_none_or(|tb| tb != target_bit) {
+                    return Err(OperationValidationError::BadInput(name));
+                }
+                if input.class.is_none_or(|class| {
+                    class != OperationClass::FullCarry && class != OperationClass::RawAdd
+                }) {
+                    return Err(OperationValidationError::BadInput(name));
+                }
+            }
+            Ok(())
+        }
+        OperationClass::FullCarry => {
+            let prev_bit = target_bit - 1;
+            if prev_bit == 0 {
+                if op.kind != GateKind::And {
+                    return Err(OperationValidationError::BadOpType);
+                }
+                for name in [wire_a, wire_b] {
+                    match name.as_str() {
+                        "x00" | "y00" => (),
+                        _ => return Err(OperationValidationError::BadInput(name)),
+                    }
+                }
+            } else {
+                if op.kind != GateKind::Or {
+                    return Err(OperationValidationError::BadOpType);
+                }
+                for (wire, name) in op.inputs.iter().zip([wire_a, wire_b]) {
+                    let Some(input) = netlist.driver(*wire).map(|driver| labels[driver]) else {
+                        return Err(OperationValidationError::BadInput(name));
+                    };
+                    if input.target_bit.is_none_or(|tb| tb != prev_bit) {
+                        return Err(OperationValidationError::BadInput(name));
+                    }
+                    if input.class.is_none_or(|class| {
+                        class != OperationClass::CombinedCarry && class != OperationClass::RawCarry
+                    }) {
+                        return Err(OperationValidationError::BadInput(name));
+                    }
+                }
+            }
+            Ok(())
+        }
+        OperationClass::Result => {
+            let target_wire = format!("z{:02}", target_bit);
+            if netlist.name(op.output) == target_wire {
+                Ok(())
+            } else {
+                Err(OperationValidationError::BadOutput(target_wire))
+            }
+        }
+        OperationClass::Error => Err(OperationValidationError::ClassIsError),
+    }
+}
+
+struct Circuit {
+    netlist: Netlist,
+    initial_values: Vec<(usize, bool)>,
+}
+
+type Buses = [Vec<(usize, usize)>; 3];
+
+impl Circuit {
+    // The x, y and z buses, which keep their wires when gate outputs are swapped
+    fn buses(&self) -> Buses {
+        ['x', 'y', 'z'].map(|prefix| self.netlist.bus(prefix))
+    }
+
+    fn exec_run(&self, [x_bus, y_bus, z_bus]: &Buses, x: usize, y: usize) -> bool {
+        let values = self.netlist.evaluate(
+            self.initial_values
+                .iter()
+                .copied()
+                .chain(netlist::bus_bits(x_bus, x))
+                .chain(netlist::bus_bits(y_bus, y)),
+        );
+        netlist::read_bus(&values, z_bus)
+            == netlist::read_bus(&values, x_bus) + netlist::read_bus(&values, y_bus)
+    }
+}
+
+fn parse_circuit(input: &str) -> Result<Circuit, SolveError> {
+    let mut builder = NetlistBuilder::new();
+    let mut initial_values: Vec<(&str, bool)> = Vec::new();
+    let mut inputs: Vec<&str> = Vec::new();
+    let mut driven: HashSet<&str> = HashSet::new();
+    for line in input.lines() {
+        let tokens: Vec<&str> = line.split(" ").collect();
+        match tokens.len() {
+            2 => {
+                let Some(wire) = tokens[0].strip_suffix(':') else {
+                    return Err(SolveError::at_token(input, line, "expected 'wire: value'"));
+                };
+                let value: u8 = parse_token(input, tokens[1])?;
+                if value > 1 {
+                    return Err(SolveError::at_token(
+                        input,
+                        tokens[1],
+                        "wire values must be 0 or 1",
+                    ));
+                }
+                initial_values.push((wire, value == 1));
+            }
+            5 => {
+                if tokens[3] != "->" {
+                    return Err(SolveError::at_token(
+                        input,
+                        line,
+                        "expected a gate like 'a AND b -> c'",
+                    ));
+                }
+                let kind: GateKind = parse_token(input, tokens[1])?;
+                if builder
+                    .gate(kind, tokens[0], tokens[2], tokens[4])
+                    .is_none()
+                {
+                    return Err(SolveError::at_token(
+                        input,
+                        tokens[4],
+                        format!("wire '{}' is driven by more than one gate", tokens[4]),
+                    ));
+                }
+                inputs.extend([tokens[0], tokens[2]]);
+                driven.insert(tokens[4]);
+            }
+            1 if line.is_empty() => (),
+            _ => {
+                return Err(SolveError::at_token(
+                    input,
+                    line,
+                    "expected a wire value or a gate",
+                ))
+            }
+        }
+    }
+    for wire in inputs {
+        if !driven.contains(wire) && !initial_values.iter().any(|(name, _)| *name == wire) {
+            return Err(SolveError::at_token(
+                input,
+                wire,
+                format!("wire '{wire}' is never driven"),
+            ));
+        }
+    }
+
+    let initial_values: Vec<(usize, bool)> = initial_values
+        .into_iter()
+        .map(|(name, value)| (builder.wire(name), value))
+        .collect();
+    Ok(Circuit {
+        netlist: builder.build()?,
+        initial_values,
+    })
+}
+
+pub fn part1(input: &str) -> Result<usize, SolveError> {
+    let Circuit {
+        netlist,
+        initial_values,
+    } = parse_circuit(input)?;
+    let values = netlist.evaluate(initial_values);
+    Ok(netlist::read_bus(&values, &netlist.bus('z')))
+}
+
+// Swaps the outputs of the gates driving the two named wires
+fn swap_wires(
+    netlist: &mut Netlist,
+    pair: &(String, String),
+    swaps: &mut Vec<(String, String)>,
+) -> Result<(), SolveError> {
+    let [first, second] = [&pair.0, &pair.1].map(|name| {
+        netlist
+            .wire(name)
+            .and_then(|wire| netlist.driver(wire))
+            .ok_or_else(|| SolveError::new(format!("no gate drives wire '{name}'")))
+    });
+    netlist.swap_outputs(first?, second?)?;
+    swaps.push(pair.clone());
+    Ok(())
+}
+
+// The gate computing `what` for bit `bit`, from one of the maps in `OperationContext`
+fn find_gate(map: &HashMap<usize, usize>, bit: usize, what: &str) -> Result<usize, SolveError> {
+    map.get(&bit)
+        .copied()
+        .ok_or_else(|| not_an_adder(format!("no gate is the {what} of bit {bit}")))
+}
+
+pub fn part2(input: &str) -> Result<Vec<String>, SolveError> {
+    let mut circuit = parse_circuit(input)?;
+    let buses = circuit.buses();
+    let netlist = &mut circuit.netlist;
+
+    // x and y need the same bits from 0 up, and z one more for the final carry
+    let bits = buses[0].len();
+    require(
+        bits >= 2
+            && buses[1].len() == bits
+            && buses[2].len() == bits + 1
+            && buses
+                .iter()
+                .all(|bus| bus.iter().enumerate().all(|(i, &(bit, _))| bit == i)),
+        || {
+            format!(
+                "expected wires x00 to x{0:02}, y00 to y{0:02} and z00 to z{1:02}",
+                bits.saturating_sub(1),
+                bits
+            )
+        },
+    )?;
+    let input_bits: HashMap<usize, (char, usize)> = ['x', 'y']
+        .into_iter()
+        .zip(&buses)
+        .flat_map(|(prefix, bus)| bus.iter().map(move |&(bit, wire)| (wire, (prefix, bit))))
+        .filter(|&(wire, _)| netlist.driver(wire).is_none())
+        .collect();
+
+    let mut context = OperationContext::new(netlist.gates().len());
+    let mut unknown_inputs: Vec<usize> = netlist
+        .gates()
+        .iter()
+        .map(|gate| {
+            gate.inputs
+                .iter()
+                .filter(|&&wire| netlist.driver(wire).is_some())
+                .count()
+        })
+        .collect();
+    let mut to_process: Vec<usize> = (0..netlist.gates().len())
+        .filter(|&gate| unknown_inputs[gate] == 0)
+        .collect();
+
+    let name_of = |netlist: &Netlist, wire: usize| netlist.name(wire).to_string();
+    while let Some(gate) = to_process.pop() {
+        let op = *netlist.gate(gate);
+        let [wire_a, wire_b] = op.inputs.map(|wire| name_of(netlist, wire));
+        if let (Some(&(a_input, a_bit_index)), Some(&(b_input, b_bit_index))) =
+            (input_bits.get(&op.inputs[0]), input_bits.get(&op.inputs[1]))
+        {
+            require(a_input != b_input && a_bit_index == b_bit_index, || {
+                format!("a gate combines {wire_a} and {wire_b}")
+            })?;
+            match op.kind {
+                GateKind::Xor => {
+                    if a_bit_index == 0 {
+                        context.make_complete(gate, OperationClass::Result, a_bit_index)?;
+                    } else {
+                        context.make_complete(gate, OperationClass::RawAdd, a_bit_index)?;
+                    }
+                }
+                GateKind::Or => {
+                    return Err(not_an_adder(format!(
+                        "a gate computes {wire_a} OR {wire_b}"
+                    )))
+                }
+                GateKind::And => {
+                    if a_bit_index == 0 {
+                        context.make_complete(gate, OperationClass::FullCarry, a_bit_index + 1)?;
+                    } else {
+                        context.make_complete(gate, OperationClass::RawCarry, a_bit_index)?;
+                    }
+                }
+            }
+        } else {
+            // Gates are only processed once both inputs are, so gate inputs are labelled
+            let [Some(a), Some(b)] = op
+                .inputs
+                .map(|wire| netlist.driver(wire).map(|driver| context.labels[driver]))
+            else {
+                return Err(not_an_adder(format!(
+                    "a gate mixes an input bit with another gate in {wire_a} {} {wire_b}",
+                    op.kind
+                )));
+            };
+            let classes = [a.class, b.class];
+            let has_one = |class| {
+                classes
+                    .iter()
+                    .filter(|&&found| found == Some(class))
+                    .count()
+                    == 1
+            };
+
+            if has_one(OperationClass::RawAdd) && has_one(OperationClass::FullCarry) {
+                if let Some(target_bit) = a.target_bit.filter(|_| a.target_bit == b.target_bit) {
+                    if op.kind == GateKind::Xor {
+                        context.make_complete(gate, OperationClass::Result, target_bit)?;
+                    } else if op.kind == GateKind::And {
+                        context.make_complete(gate, OperationClass::CombinedCarry, target_bit)?;
+                    } else {
+                        context.make_error(gate)?;
+                    }
+                } else {
+                    context.make_error(gate)?;
+                }
+            } else if has_one(OperationClass::CombinedCarry) && has_one(OperationClass::RawCarry) {
+                match a.target_bit.filter(|_| a.target_bit == b.target_bit) {
+                    Some(target_bit) if op.kind == GateKind::Or => {
+                        context.make_complete(gate, OperationClass::FullCarry, target_bit + 1)?;
+                    }
+                    _ => context.make_error(gate)?,
+                }
+            } else {
+                context.make_error(gate)?;
+            }
+        }
+        if context.labels[gate].class != Some(OperationClass::Error) {
+            for &dep in netlist.readers(op.output) {
+                unknown_inputs[dep] -= 1;
+                if unknown_inputs[dep] == 0 {
+                    to_process.push(dep);
+                }
+            }
+        }
+    }
+    require(
+        context.result.contains_key(&0) && context.full_carry.contains_key(&1),
+        || String::from("bit 0 is not a half adder of x00 and y00"),
+    )?;
+
+    let mut swapped_wires: Vec<(String, String)> = Vec::new();
+    let mut bad_raw_adds: Vec<usize> = Vec::new();
+
+    // Solve for bad results
+    for i in 1..bits {
+        let radd = *netlist.gate(find_gate(&context.raw_add, i, "sum")?);
+        let target_out = format!("z{:02}", i);
+        let mut needed_swap: Option<(String, String)> = None;
+        for &dep in netlist.readers(radd.output) {
+            if netlist.gate(dep).kind == GateKind::Xor && context.labels[dep].is_incomplete() {
+                context.make_complete(dep, OperationClass::Result, i)?;
+                let dep_output = name_of(netlist, netlist.gate(dep).output);
+                if dep_output != target_out {
+                    needed_swap = Some((dep_output, target_out.clone()));
+                }
+            }
+        }
+        if let Some(&result) = context.result.get(&i) {
+            let result_wire = name_of(netlist, netlist.gate(result).output);
+            if result_wire != target_out && needed_swap.is_none() {
+                needed_swap = Some((result_wire, target_out.clone()));
+            }
+        } else {
+            bad_raw_adds.push(radd.output);
+        }
+        if let Some(swap) = needed_swap {
+            swap_wires(netlist, &swap, &mut swapped_wires)?;
+        }
+    }
+
+    for bad_radd in bad_raw_adds {
+        // Swaps only move outputs between gates, so the wire is still driven and its gate labelled
+        let radd = netlist.driver(bad_radd).unwrap();
+        let target_bit = context.labels[radd].target_bit.unwrap();
+        let target_out = format!("z{:02}", target_bit);
+        let best = *context
+            .unused
+            .iter()
+            .max_by_key(|&&candidate| {
+                let op = netlist.gate(candidate);
+                let mut score = 0;
+                if netlist.name(op.output) == target_out {
+                    score += 1;
+                }
+                if op.kind != GateKind::Xor {
+                    score = 0;
+                } else {
+                    score += 1;
+                }
+                score
+            })
+            .ok_or_else(|| not_an_adder(format!("no gate is left to compute {target_out}")))?;
+        let best_op = *netlist.gate(best);
+        let mut needed_swaps: Vec<(String, String)> = Vec::new();
+        if netlist.name(best_op.output) != target_out {
+            let target_gate = netlist
+                .wire(&target_out)
+                .and_then(|wire| netlist.driver(wire));
+            require(
+                target_gate.is_some_and(|target_gate| context.unused.contains(&target_gate)),
+                || format!("{target_out} is driven by a gate that is already part of the adder"),
+            )?;
+            needed_swaps.push((name_of(netlist, best_op.output), target_out.clone()));
+        }
+        let radd_output = name_of(netlist, netlist.gate(radd).output);
+        for (i, input) in best_op.inputs.into_iter().enumerate() {
+            let Some(driver) = netlist.driver(input) else {
+                return Err(not_an_adder(format!(
+                    "the gate chosen for {target_out} reads the input {}",
+                    netlist.name(input)
+                )));
+            };
+            if netlist.gate(driver).kind != GateKind::Or {
+                require(i == 0 || needed_swaps.len() < 2, || {
+                    format!("{target_out} would need more than two swaps")
+                })?;
+                needed_swaps.push((name_of(netlist, input), radd_output.clone()));
+            }
+        }
+        context.make_complete(best, OperationClass::Result, target_bit)?;
+        for swap in needed_swaps {
+            swap_wires(netlist, &swap, &mut swapped_wires)?;
+        }
+    }
+
+    for i in 1..bits {
+        let radd = *netlist.gate(find_gate(&context.raw_add, i, "sum")?);
+        let mut ccarry = context.combined_carry.get(&i).copied();
+        if ccarry.is_none() {
+            for &dep in netlist.readers(radd.output) {
+                if netlist.gate(dep).kind == GateKind::And {
+                    require(ccarry.is_none(), || {
+                        format!("the sum of bit {i} is read by more than one AND gate")
+                    })?;
+                    if context.labels[dep].is_incomplete() {
+                        context.make_complete(dep, OperationClass::CombinedCarry, i)?;
+                        ccarry = Some(dep);
+                    }
+                }
+            }
+        }
+    }
+
+    for i in 1..bits {
+        if context.full_carry.contains_key(&(i + 1)) {
+            continue;
+        }
+        let rcarry = netlist.gate(find_gate(&context.raw_carry, i, "carry")?);
+        let ccarry = netlist.gate(find_gate(&context.combined_carry, i, "combined carry")?);
+        let rcarry_deps = netlist.readers(rcarry.output);
+        let ccarry_deps = netlist.readers(ccarry.output);
+        require(
+            rcarry_deps == ccarry_deps
+                && rcarry_deps.len() == 1
+                && context.labels[rcarry_deps[0]].is_incomplete(),
+            || format!("the carries of bit {i} don't meet in one unlabelled gate"),
+        )?;
+        context.make_complete(rcarry_deps[0], OperationClass::FullCarry, i + 1)?;
+    }
+
+    for gate in 0..netlist.gates().len() {
+        if let Err(validation_err) = logical_validate(gate, netlist, &context.labels) {
+            return Err(not_an_adder(format!(
+                "the gate driving {} is still wrong after swapping: {validation_err}",
+                netlist.name(netlist.gate(gate).output)
+            )));
+        }
+    }
+
+    // Validate
+    for x_shift in 0..bits {
+        for y_shift in 0..bits {
+            for extra_x_shift in 0..bits {
+                let pairs = [
+                    ((1 << (x_shift + 1)) << extra_x_shift, 1 << y_shift),
+                    (!((1 << (x_shift + 1)) << extra_x_shift), !(1 << y_shift)),
+                ];
+                for (x, y) in pairs {
+                    require(circuit.exec_run(&buses, x, y), || {
+                        format!("the swapped circuit adds {x:#x} and {y:#x} wrongly")
+                    })?;
+                }
+            }
+        }
+    }
+
+    let mut sorted_swaps: Vec<String> = swapped_wires
+        .drain(..)
+        .flat_map(|swap| [swap.0, swap.1])
+        .collect();
+    sorted_swaps.sort();
+    Ok(sorted_swaps)
+}
+
+impl crate::Solver for Solver {
+    fn solve_part1(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn solve_part2(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part2(input)?.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // xorshift, so the generated adders are the same on every run
+    struct Rng(u64);
+
+    impl Rng {
+        fn below(&mut self, n: usize) -> usize {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            (self.0 % n as u64) as usize
+        }
+    }
+
+    // Which two wires of a bit have their gates swapped, named as in `adder`
+    #[derive(Clone, Copy, Debug)]
+    enum Swap {
+        Zc,
+        Zd,
+        Sr,
+        Zr,
+    }
+
+    // A ripple-carry adder with one swap at each of some random bits at least 3 apart, in a random
+    // order, along with the swapped wires. Bit i adds s = x XOR y and r = x AND y to the carry c
+    // as z = s XOR c, d = c AND s and the next carry d OR r
+    fn adder(seed: u64, bits: usize, swaps: &[Swap]) -> (String, Vec<String>) {
+        let mut rng = Rng(seed);
+        let carry = |i: usize| {
+            if i == bits {
+                format!("z{bits:02}")
+            } else {
+                format!("c{i:02}")
+            }
+        };
+        let wire = |prefix: &str, i: usize| format!("{prefix}{i:02}");
+        let mut gates: Vec<(String, &str, String, String)> = vec![
+            (wire("x", 0), "XOR", wire("y", 0), wire("z", 0)),
+            (wire("x", 0), "AND", wire("y", 0), carry(1)),
+        ];
+        for i in 1..bits {
+            gates.extend([
+                (wire("x", i), "XOR", wire("y", i), wire("s", i)),
+                (wire("x", i), "AND", wire("y", i), wire("r", i)),
+                (wire("s", i), "XOR", carry(i), wire("z", i)),
+                (carry(i), "AND", wire("s", i), wire("d", i)),
+                (wire("d", i), "OR", wire("r", i), carry(i + 1)),
+            ]);
+        }
+
+        let mut swapped: Vec<String> = Vec::new();
+        let mut swapped_bits: Vec<usize> = Vec::new();
+        for &swap in swaps {
+            let i = loop {
+                let i = 2 + rng.below(bits - 4);
+                if swapped_bits.iter().all(|&j| i.abs_diff(j) > 2) {
+                    break i;
+                }
+            };
+            swapped_bits.push(i);
+            let (first, second) = match swap {
+                Swap::Zc => (wire("z", i), carry(i + 1)),
+                Swap::Zd => (wire("z", i), wire("d", i)),
+                Swap::Sr => (wire("s", i), wire("r", i)),
+                Swap::Zr => (wire("z", i), wire("r", i)),
+            };
+            for gate in &mut gates {
+                if gate.3 == first {
+                    gate.3 = second.clone();
+                } else if gate.3 == second {
+                    gate.3 = first.clone();
+                }
+            }
+            swapped.extend([first, second]);
+        }
+        swapped.sort();
+
+        for i in (1..gates.len()).rev() {
+            gates.swap(i, rng.below(i + 1));
+        }
+        let mut input = String::new();
+        for prefix in ["x", "y"] {
+            for i in 0..bits {
+                input += &format!("{}: {}\n", wire(prefix, i), rng.below(2));
+            }
+        }
+        input += "\n";
+        for (a, kind, b, output) in gates {
+            let (a, b) = if rng.below(2) == 0 { (a, b) } else { (b, a) };
+            input += &format!("{a} {kind} {b} -> {output}\n");
+        }
+        (input, swapped)
+    }
+
+    #[test]
+    fn part2_repairs_generated_adders() {
+        use Swap::*;
+        let cases = [
+            (1, [Zc, Zd, Sr, Zc]),
+            (2, [Zc, Zc, Zd, Sr]),
+            (3, [Sr, Zd, Zc, Zd]),
+            (4, [Zr, Zc, Zd, Sr]),
+            (5, [Zr, Zr, Sr, Sr]),
+            (6, [Zd, Zr, Zc, Sr]),
+        ];
+        for (seed, swaps) in cases {
+            let (input, swapped) = adder(seed, 45, &swaps);
+            assert_eq!(part2(&input).unwrap(), swapped, "seed {seed}, {swaps:?}");
+        }
+    }
+
+    #[test]
+    fn part2_takes_its_width_from_the_x_wires() {
+        let (input, swapped) = adder(7, 12, &[Swap::Zc, Swap::Sr]);
+        assert_eq!(part2(&input).unwrap(), swapped);
+    }
+
+    #[test]
+    fn part1_adds_on_an_unswapped_adder() {
+        let (input, _) = adder(8, 45, &[]);
+        let value = |prefix: char| -> usize {
+            input
+                .lines()
+                .filter_map(|line| line.strip_prefix(prefix)?.split_once(": "))
+                .map(|(bit, value)| {
+                    value.parse::<usize>().unwrap() << bit.parse::<usize>().unwrap()
+                })
+                .sum()
+        };
+        assert_eq!(part1(&input).unwrap(), value('x') + value('y'));
+    }
+
+    #[test]
+    fn part2_rejects_circuits_that_are_not_adders() {
+        let (input, _) = adder(9, 12, &[]);
+        let err = part2(&input.replace(" -> z12", " -> c12")).unwrap_err();
+        assert_eq!(
+            err.message,
+            "the circuit is not a ripple-carry adder with swapped outputs: expected wires x00 to \
+             x11, y00 to y11 and z00 to z12"
+        );
+
+        let or_input = input
+            .lines()
+            .map(|line| {
+                if line.starts_with("x05 XOR y05") || line.starts_with("y05 XOR x05") {
+                    line.replace("XOR", "OR")
+                } else {
+                    line.to_string()
+                }
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        let err = part2(&or_input).unwrap_err();
+        assert!(
+            ["a gate computes x05 OR y05", "a gate computes y05 OR x05"]
+                .iter()
+                .any(|reason| err.message.ends_with(reason)),
+            "{}",
+            err.message
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use std::iter::zip;
+
+use crate::{parse, solvers::Registration, Params, SolveError};
+
+pub struct Solver {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2024,
+    day: 25,
+    title: "Code Chronicle",
+    parts: &[1],
+    solver: || Box::new(Solver {}),
+};
+
+#[derive(Debug)]
+struct Profile {
+    heights: [u8; 5],
+}
+
+impl Profile {
+    fn new() -> Profile {
+        Profile { heights: [0u8; 5] }
+    }
+
+    fn parse_line(&mut self, line: &str) -> bool {
+        if line.len() != 5 {
+            return false;
+        }
+        for (i, c) in line.chars().enumerate() {
+            match c {
+                '.' => (),
+                '#' => self.heights[i] += 1,
+                _ => return false,
+            }
+        }
+        true
+    }
+
+    fn fits_with(&self, other: &Profile) -> bool {
+        !zip(self.heights, other.heights).any(|(x, y)| x + y > 5)
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, SolveError> {
+    let mut locks: Vec<Profile> = Vec::new();
+    let mut keys: Vec<Profile> = Vec::new();
+
+    for section in parse::sections(input) {
+        let lines: Vec<&str> = section.lines().collect();
+        let Some((top, rows)) = lines.split_first().filter(|(_, rows)| rows.len() >= 5) else {
+            return Err(SolveError::at_token(
+                input,
+                section,
+                "expected a schematic with 5 rows between its top and bottom",
+            ));
+        };
+        let is_lock = if top.chars().all(|c| c == '#') {
+            true
+        } else if top.chars().all(|c| c == '.') {
+            false
+        } else {
+            return Err(SolveError::at_token(
+                input,
+                top,
+                "expected the top row to be all '#' or all '.'",
+            ));
+        };
+        let mut working = Profile::new();
+        for line in &rows[..5] {
+            if !working.parse_line(line) {
+                return Err(SolveError::at_token(
+                    input,
+                    line,
+                    "expected 5 characters of '#' or '.'",
+                ));
+            }
+        }
+        if is_lock {
+            locks.push(working);
+        } else {
+            keys.push(working);
+        }
+    }
+
+    let num_fits: usize = keys
+        .iter()
+        .flat_map(|key| {
+            locks
+                .iter()
+                .map(|lock| if lock.fits_with(key) { 1 } else { 0 })
+        })
+        .sum();
+    Ok(num_fits)
+}
+
+impl crate::Solver for Solver {
+    fn solve_part1(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn solve_part2(&self, _input: &str, _params: &Params) -> Result<String, SolveError> {
+        // Day 25 only has one puzzle
+        Ok(String::from("Merry Christmas!"))
+    }
+}
@@ -0,0 +1,51 @@
+use regex::Regex;
+
+use crate::{parse_token, solvers::Registration, Params, SolveError};
+
+pub struct Solver {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2024,
+    day: 3,
+    title: "Mull It Over",
+    parts: &[1, 2],
+    solver: || Box::new(Solver {}),
+};
+
+fn sum_muls(input: &str, respect_toggles: bool) -> Result<usize, SolveError> {
+    let re = Regex::new(r"(mul\(([0-9]+),([0-9]+)\))|(do\(\))|(don't\(\))").unwrap();
+    let mut acc: usize = 0;
+    let mut enabled = true;
+    for mat in re.captures_iter(input) {
+        let full = mat.get(0).unwrap().as_str();
+        if full.starts_with("mul") && enabled {
+            acc += parse_token::<usize>(input, mat.get(2).unwrap().as_str())?
+                * parse_token::<usize>(input, mat.get(3).unwrap().as_str())?;
+        } else if !respect_toggles {
+            continue;
+        } else if full.starts_with("don't") {
+            enabled = false;
+        } else if full.starts_with("do") {
+            enabled = true;
+        }
+    }
+    Ok(acc)
+}
+
+pub fn part1(input: &str) -> Result<usize, SolveError> {
+    sum_muls(input, false)
+}
+
+pub fn part2(input: &str) -> Result<usize, SolveError> {
+    sum_muls(input, true)
+}
+
+impl crate::Solver for Solver {
+    fn solve_part1(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn solve_part2(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part2(input)?.to_string())
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{
+    geometry::{Point, Vector},
+    grid::Grid,
+    solvers::Registration,
+    Params, SolveError,
+};
+
+pub struct Solver {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2024,
+    day: 4,
+    title: "Ceres Search",
+    parts: &[1, 2],
+    solver: || Box::new(Solver {}),
+};
+
+pub fn part1(input: &str) -> Result<usize, SolveError> {
+    let grid = Grid::parse(input)?;
+
+    fn search(pos: Point, vector: Vector, grid: &Grid<char>) -> bool {
+        let mut maybe_pos = Some(pos);
+        for target in "XMAS".chars() {
+            match maybe_pos {
+                Some(pos) if grid[pos] == target => maybe_pos = grid.offset(pos, vector),
+                _ => return false,
+            }
+        }
+        true
+    }
+
+    Ok(grid
+        .positions()
+        .map(|pos| {
+            Vector::NEIGHBOURS8
+                .iter()
+                .filter(|vector| search(pos, **vector, &grid))
+                .count()
+        })
+        .sum())
+}
+
+pub fn part2(input: &str) -> Result<usize, SolveError> {
+    let grid = Grid::parse(input)?;
+    let mut found: usize = 0;
+
+    fn search(x: usize, y: usize, grid: &Grid<char>) -> bool {
+        let ul = grid[Point::new(x - 1, y - 1)];
+        let ur = grid[Point::new(x + 1, y - 1)];
+        let bl = grid[Point::new(x - 1, y + 1)];
+        let br = grid[Point::new(x + 1, y + 1)];
+        let down_diagonal_ok = (ul == 'M' && br == 'S') || (ul == 'S' && br == 'M');
+        let up_diagonal_ok = (bl == 'M' && ur == 'S') || (bl == 'S' && ur == 'M');
+        down_diagonal_ok && up_diagonal_ok && grid[Point::new(x, y)] == 'A'
+    }
+
+    for x in 1..grid.width().saturating_sub(1) {
+        for y in 1..grid.height().saturating_sub(1) {
+            if search(x, y, &grid) {
+                found += 1;
+            }
+        }
+    }
+    Ok(found)
+}
+
+impl crate::Solver for Solver {
+    fn solve_part1(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn solve_part2(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part2(input)?.to_string())
+    }
+}
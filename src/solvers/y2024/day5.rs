@@ -0,0 +1,139 @@
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
+
+use crate::{parse, parse_token, solvers::Registration, Params, SolveError};
+
+pub struct Solver {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2024,
+    day: 5,
+    title: "Print Queue",
+    parts: &[1, 2],
+    solver: || Box::new(Solver {}),
+};
+
+struct Rules {
+    before: HashMap<usize, HashSet<usize>>,
+    after: HashMap<usize, HashSet<usize>>,
+}
+
+impl Rules {
+    fn is_ordered(&self, updates: &[usize]) -> bool {
+        let mut working_before: HashSet<usize> = HashSet::new();
+        let mut working_after: HashSet<usize> = HashSet::from_iter(updates.iter().copied());
+        for val in updates {
+            working_after.remove(val);
+            let before_ok = self
+                .before
+                .get(val)
+                .map(|bset| bset.is_disjoint(&working_before))
+                .unwrap_or(true);
+            let after_ok = self
+                .after
+                .get(val)
+                .map(|aset| aset.is_disjoint(&working_after))
+                .unwrap_or(true);
+            if !(before_ok && after_ok) {
+                return false;
+            }
+            working_before.insert(*val);
+        }
+        true
+    }
+
+    fn sort(&self, updates: &mut [usize]) {
+        updates.sort_by(|a, b| {
+            if a == b {
+                return Ordering::Equal;
+            }
+            if let Some(before) = self.before.get(a) {
+                if before.contains(b) {
+                    return Ordering::Greater;
+                }
+            }
+            if let Some(before) = self.before.get(b) {
+                if before.contains(a) {
+                    return Ordering::Less;
+                }
+            }
+            Ordering::Equal
+        });
+    }
+}
+
+fn parse(input: &str) -> Result<(Rules, Vec<Vec<usize>>), SolveError> {
+    let [rules_section, updates_section] = parse::sections(input)[..] else {
+        return Err(SolveError::new(
+            "expected ordering rules and updates separated by a blank line",
+        ));
+    };
+    let mut rules = Rules {
+        before: HashMap::new(),
+        after: HashMap::new(),
+    };
+    for line in rules_section.lines() {
+        let Some((first, second)) = line.split_once("|") else {
+            return Err(SolveError::at_token(
+                input,
+                line,
+                "expected an ordering rule like 'X|Y'",
+            ));
+        };
+        let first = parse_token(input, first)?;
+        let second = parse_token(input, second)?;
+        if let Some(before_set) = rules.before.get_mut(&first) {
+            before_set.insert(second);
+        } else {
+            let mut before_set = HashSet::new();
+            before_set.insert(second);
+            assert!(rules.before.insert(first, before_set).is_none());
+        }
+        if let Some(after_set) = rules.after.get_mut(&second) {
+            after_set.insert(first);
+        } else {
+            let mut after_set = HashSet::new();
+            after_set.insert(first);
+            assert!(rules.after.insert(second, after_set).is_none());
+        }
+    }
+    let all_updates: Vec<Vec<usize>> = updates_section
+        .lines()
+        .map(|line| parse::list(input, line, ","))
+        .collect::<Result<_, _>>()?;
+    Ok((rules, all_updates))
+}
+
+pub fn part1(input: &str) -> Result<usize, SolveError> {
+    let (rules, all_updates) = parse(input)?;
+    let acc: usize = all_updates
+        .iter()
+        .filter(|updates| rules.is_ordered(updates))
+        .map(|updates| updates[updates.len() / 2])
+        .sum();
+    Ok(acc)
+}
+
+pub fn part2(input: &str) -> Result<usize, SolveError> {
+    let (rules, all_updates) = parse(input)?;
+    let mut acc: usize = 0;
+    for mut updates in all_updates {
+        if !rules.is_ordered(&updates) {
+            rules.sort(&mut updates);
+            acc += updates[updates.len() / 2];
+        }
+    }
+    Ok(acc)
+}
+
+impl crate::Solver for Solver {
+    fn solve_part1(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn solve_part2(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part2(input)?.to_string())
+    }
+}
@@ -0,0 +1,120 @@
+use std::collections::HashSet;
+
+use crate::{
+    geometry::{Direction, Point},
+    grid::Grid,
+    solvers::Registration,
+    visualize::{self, Frame},
+    Params, SolveError,
+};
+
+pub struct Solver {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2024,
+    day: 6,
+    title: "Guard Gallivant",
+    parts: &[1, 2],
+    solver: || Box::new(Solver {}),
+};
+
+struct Lab {
+    blocked: Grid<bool>,
+    start: Point,
+}
+
+impl Lab {
+    fn parse(input: &str) -> Result<Lab, SolveError> {
+        let map = Grid::parse(input)?;
+        let Some(start) = map.find(&'^') else {
+            return Err(SolveError::new("could not find the guard ('^')"));
+        };
+        Ok(Lab {
+            blocked: map.map(|c| *c == '#'),
+            start,
+        })
+    }
+
+    fn draw(&self, visited: &HashSet<Point>, pos: Point, direction: Direction) -> Frame {
+        let mut frame = Frame::new(self.blocked.width(), self.blocked.height(), '.');
+        for blocked in self.blocked.find_all(&true) {
+            frame.set(blocked, '#');
+        }
+        for &seen in visited {
+            frame.set(seen, 'X');
+        }
+        frame.set(pos, direction.to_char());
+        frame
+    }
+
+    fn patrol(&self, animate: bool) -> HashSet<Point> {
+        let mut visited: HashSet<Point> = HashSet::new();
+        let mut pos = self.start;
+        let mut direction = Direction::North;
+        loop {
+            visited.insert(pos);
+            if animate {
+                visualize::frame(|| self.draw(&visited, pos, direction));
+            }
+            if let Some(npos) = self.blocked.step(pos, direction) {
+                if self.blocked[npos] {
+                    direction = direction.rotate_cw();
+                } else {
+                    pos = npos;
+                }
+            } else {
+                break;
+            }
+        }
+        visited
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, SolveError> {
+    Ok(Lab::parse(input)?.patrol(true).len())
+}
+
+pub fn part2(input: &str) -> Result<usize, SolveError> {
+    let mut lab = Lab::parse(input)?;
+    let visited = lab.patrol(false);
+    let start = lab.start;
+    let mut trial_path: HashSet<(Point, Direction)> = HashSet::new();
+    let mut found: usize = 0;
+    for obstacle in visited {
+        if obstacle == start {
+            continue;
+        }
+        trial_path.clear();
+        let mut pos = start;
+        let mut direction = Direction::North;
+        lab.blocked[obstacle] = true;
+        loop {
+            if !trial_path.insert((pos, direction)) {
+                // We are looping
+                found += 1;
+                break;
+            }
+            if let Some(npos) = lab.blocked.step(pos, direction) {
+                if lab.blocked[npos] {
+                    direction = direction.rotate_cw();
+                } else {
+                    pos = npos;
+                }
+            } else {
+                break;
+            }
+        }
+        lab.blocked[obstacle] = false;
+    }
+    Ok(found)
+}
+
+impl crate::Solver for Solver {
+    fn solve_part1(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn solve_part2(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part2(input)?.to_string())
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{parse, parse_token, solvers::Registration, Params, SolveError};
+
+pub struct Solver {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2024,
+    day: 7,
+    title: "Bridge Repair",
+    parts: &[1, 2],
+    solver: || Box::new(Solver {}),
+};
+
+fn solve_operands(target: usize, acc: usize, operands: &[usize], allow_concat: bool) -> bool {
+    if operands.is_empty() {
+        return target == acc;
+    }
+
+    if acc > target {
+        return false;
+    }
+
+    let num_digits = 1 + operands[0].checked_ilog10().unwrap_or(0);
+
+    solve_operands(target, acc + operands[0], &operands[1..], allow_concat)
+        || solve_operands(target, acc * operands[0], &operands[1..], allow_concat)
+        || (allow_concat
+            && solve_operands(
+                target,
+                acc * 10_usize.pow(num_digits) + operands[0],
+                &operands[1..],
+                allow_concat,
+            ))
+}
+
+fn calibration_total(input: &str, allow_concat: bool) -> Result<usize, SolveError> {
+    let mut acc: usize = 0;
+    for line in input.lines() {
+        let Some((target, operands)) = line.split_once(": ") else {
+            return Err(SolveError::at_token(
+                input,
+                line,
+                "expected an equation like 'X: A B C'",
+            ));
+        };
+        let target: usize = parse_token(input, target)?;
+        let operands: Vec<usize> = parse::list(input, operands, " ")?;
+        if solve_operands(target, 0, &operands, allow_concat) {
+            acc += target;
+        }
+    }
+    Ok(acc)
+}
+
+pub fn part1(input: &str) -> Result<usize, SolveError> {
+    calibration_total(input, false)
+}
+
+pub fn part2(input: &str) -> Result<usize, SolveError> {
+    calibration_total(input, true)
+}
+
+impl crate::Solver for Solver {
+    fn solve_part1(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn solve_part2(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part2(input)?.to_string())
+    }
+}
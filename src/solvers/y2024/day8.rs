@@ -0,0 +1,68 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{geometry::Point, grid::Grid, solvers::Registration, Params, SolveError};
+
+pub struct Solver {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2024,
+    day: 8,
+    title: "Resonant Collinearity",
+    parts: &[1, 2],
+    solver: || Box::new(Solver {}),
+};
+
+fn count_antinodes(input: &str, resonant: bool) -> Result<usize, SolveError> {
+    let grid = Grid::parse(input)?;
+    let mut antinodes: HashSet<Point> = HashSet::new();
+    let mut antennas: HashMap<char, Vec<Point>> = HashMap::new();
+
+    for (pos, c) in grid.iter() {
+        if *c != '.' {
+            antennas.entry(*c).or_default().push(pos);
+        }
+    }
+    for ants in antennas.values() {
+        for (i, &loc) in ants.iter().enumerate() {
+            for &other in &ants[i + 1..] {
+                let diff = loc - other;
+                let back = -diff;
+                if resonant {
+                    let mut anti_a = Some(loc);
+                    while let Some(pos) = anti_a {
+                        antinodes.insert(pos);
+                        anti_a = grid.offset(pos, diff);
+                    }
+
+                    let mut anti_b = Some(other);
+                    while let Some(pos) = anti_b {
+                        antinodes.insert(pos);
+                        anti_b = grid.offset(pos, back);
+                    }
+                } else {
+                    antinodes.extend(grid.offset(loc, diff));
+                    antinodes.extend(grid.offset(other, back));
+                }
+            }
+        }
+    }
+    Ok(antinodes.len())
+}
+
+pub fn part1(input: &str) -> Result<usize, SolveError> {
+    count_antinodes(input, false)
+}
+
+pub fn part2(input: &str) -> Result<usize, SolveError> {
+    count_antinodes(input, true)
+}
+
+impl crate::Solver for Solver {
+    fn solve_part1(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn solve_part2(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part2(input)?.to_string())
+    }
+}
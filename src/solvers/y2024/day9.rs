@@ -0,0 +1,147 @@
+use crate::{solvers::Registration, Params, SolveError};
+
+pub struct Solver {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2024,
+    day: 9,
+    title: "Disk Fragmenter",
+    parts: &[1, 2],
+    solver: || Box::new(Solver {}),
+};
+
+fn parse_disk(input: &str) -> Result<Vec<Option<u32>>, SolveError> {
+    let mut count: u32 = 0;
+    let mut empty = false;
+    let mut fs: Vec<Option<u32>> = Vec::new();
+    for (i, c) in input.char_indices() {
+        let Some(repts) = c.to_digit(10) else {
+            return Err(SolveError::at_token(
+                input,
+                &input[i..i + c.len_utf8()],
+                format!("expected a digit, found {c:?}"),
+            ));
+        };
+        if empty {
+            empty = false;
+            fs.extend(std::iter::repeat_n(None, repts as usize));
+        } else {
+            empty = true;
+            count += 1;
+            fs.extend(std::iter::repeat_n(Some(count - 1), repts as usize));
+        }
+    }
+    if fs.is_empty() {
+        return Err(SolveError::new("disk map is empty"));
+    }
+    Ok(fs)
+}
+
+fn checksum(fs: &[Option<u32>]) -> usize {
+    fs.iter()
+        .enumerate()
+        .map(|(i, b)| i * b.unwrap_or(0) as usize)
+        .sum()
+}
+
+pub fn part1(input: &str) -> Result<usize, SolveError> {
+    let mut fs = parse_disk(input)?;
+    let mut low: usize = 0;
+    let mut high = fs.len() - 1;
+    while low < high {
+        if fs[low].is_some() {
+            low += 1;
+        } else if fs[high].is_none() {
+            high -= 1;
+        } else {
+            fs.swap(low, high);
+        }
+    }
+    Ok(checksum(&fs))
+}
+
+pub fn part2(input: &str) -> Result<usize, SolveError> {
+    let mut gaps: Vec<(usize, usize)> = Vec::new();
+    let mut fs = parse_disk(input)?;
+
+    let mut low: usize = 0;
+
+    fn count_free_space_at(data: &[Option<u32>], i: usize) -> usize {
+        let mut found: usize = 0;
+        while data.get(i + found).map(|v| v.is_none()).unwrap_or(false) {
+            found += 1;
+        }
+        found
+    }
+
+    while low < fs.len() {
+        if fs[low].is_none() {
+            let space = count_free_space_at(&fs, low);
+            gaps.push((low, space));
+            low += space;
+        } else {
+            low += 1;
+        }
+    }
+
+    let mut high = fs.len() - 1;
+
+    // Counts backwards...
+    fn count_block_size_at(data: &[Option<u32>], i: usize) -> usize {
+        let mut found: usize = 1;
+        let target = data.get(i).unwrap().unwrap();
+        while found <= i
+            && data
+                .get(i - found)
+                .and_then(|v| v.map(|iv| iv == target))
+                .unwrap_or(false)
+        {
+            found += 1;
+        }
+        found
+    }
+
+    low = 0;
+    'defrag: while high > low {
+        if fs[high].is_some() {
+            let space = count_block_size_at(&fs, high);
+            for (i, (gap_index, gap_size)) in gaps.iter().enumerate() {
+                if *gap_index > high - (space - 1) {
+                    break;
+                }
+                if *gap_size >= space {
+                    for i in 0..space {
+                        fs.swap(gap_index + i, high - i);
+                    }
+
+                    if *gap_size == space {
+                        gaps.remove(i);
+                    } else {
+                        gaps[i].0 += space;
+                        gaps[i].1 -= space;
+                    }
+                    high -= space;
+                    continue 'defrag;
+                }
+            }
+            if space > high {
+                break 'defrag;
+            }
+            high -= space;
+        } else {
+            high -= 1;
+        }
+    }
+
+    Ok(checksum(&fs))
+}
+
+impl crate::Solver for Solver {
+    fn solve_part1(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn solve_part2(&self, input: &str, _params: &Params) -> Result<String, SolveError> {
+        Ok(part2(input)?.to_string())
+    }
+}
@@ -0,0 +1,265 @@
+use std::{
+    fmt::Display,
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use crate::geometry::Point;
+
+// Frames are only built while a sink is installed, so solvers pay one atomic load per frame otherwise
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static SINK: Mutex<Option<Installed>> = Mutex::new(None);
+
+struct Installed {
+    sink: Box<dyn FrameSink>,
+    error: Option<io::Error>,
+}
+
+pub struct Frame {
+    rows: Vec<Vec<char>>,
+}
+
+impl Frame {
+    pub fn new(width: usize, height: usize, fill: char) -> Frame {
+        Frame {
+            rows: vec![vec![fill; width]; height],
+        }
+    }
+
+    pub fn from_rows<R, T>(rows: R) -> Frame
+    where
+        R: IntoIterator<Item = T>,
+        T: IntoIterator<Item = char>,
+    {
+        Frame {
+            rows: rows
+                .into_iter()
+                .map(|row| row.into_iter().collect())
+                .collect(),
+        }
+    }
+
+    // Tiles outside of the frame are ignored
+    pub fn set(&mut self, pos: Point, tile: char) {
+        if let Some(current) = self.rows.get_mut(pos.y).and_then(|row| row.get_mut(pos.x)) {
+            *current = tile;
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.rows.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn rows(&self) -> &[Vec<char>] {
+        &self.rows
+    }
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in &self.rows {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+// Tiles follow the puzzle descriptions, e.g. `X` for visited and `O` for boxes and best paths
+fn color(tile: char) -> [u8; 3] {
+    match tile {
+        '#' => [110, 110, 110],
+        '.' | ' ' => [20, 20, 20],
+        'O' | '[' | ']' => [220, 170, 60],
+        '@' | '^' | '>' | 'v' | '<' => [230, 60, 60],
+        'S' | 'E' => [70, 210, 70],
+        'X' => [70, 130, 230],
+        _ => [230, 230, 230],
+    }
+}
+
+pub trait FrameSink: Send {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()>;
+    fn finish(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+// Redraws every frame in place with ANSI escapes, waiting `delay` between frames
+pub struct Terminal<W: Write + Send> {
+    writer: W,
+    delay: Duration,
+    started: bool,
+}
+
+impl<W: Write + Send> Terminal<W> {
+    pub fn new(writer: W, delay: Duration) -> Terminal<W> {
+        Terminal {
+            writer,
+            delay,
+            started: false,
+        }
+    }
+}
+
+impl<W: Write + Send> FrameSink for Terminal<W> {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        if !self.started {
+            write!(self.writer, "\x1b[2J")?;
+            self.started = true;
+        }
+        let mut screen = String::from("\x1b[H");
+        for row in frame.rows() {
+            let mut current = None;
+            for &tile in row {
+                let [r, g, b] = color(tile);
+                if current != Some([r, g, b]) {
+                    screen.push_str(&format!("\x1b[38;2;{r};{g};{b}m"));
+                    current = Some([r, g, b]);
+                }
+                screen.push(tile);
+            }
+            screen.push_str("\x1b[0m\x1b[K\n");
+        }
+        self.writer.write_all(screen.as_bytes())?;
+        self.writer.flush()?;
+        thread::sleep(self.delay);
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ppm,
+    Pgm,
+}
+
+// Writes every frame as a numbered image in `dir`, with each tile drawn as a square of `scale` pixels
+pub struct Images {
+    dir: PathBuf,
+    format: ImageFormat,
+    scale: usize,
+    count: usize,
+}
+
+impl Images {
+    pub fn new(dir: PathBuf, format: ImageFormat, scale: usize) -> io::Result<Images> {
+        fs::create_dir_all(&dir)?;
+        Ok(Images {
+            dir,
+            format,
+            scale: scale.max(1),
+            count: 0,
+        })
+    }
+}
+
+impl FrameSink for Images {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        let (magic, extension) = match self.format {
+            ImageFormat::Ppm => ("P6", "ppm"),
+            ImageFormat::Pgm => ("P5", "pgm"),
+        };
+        let path = self.dir.join(format!("frame{:06}.{extension}", self.count));
+        let mut writer = BufWriter::new(File::create(path)?);
+        let width = frame.width();
+        writeln!(
+            writer,
+            "{magic}\n{} {}\n255",
+            width * self.scale,
+            frame.height() * self.scale
+        )?;
+        for row in frame.rows() {
+            let mut line: Vec<u8> = Vec::new();
+            for x in 0..width {
+                let [r, g, b] = color(row.get(x).copied().unwrap_or(' '));
+                for _ in 0..self.scale {
+                    match self.format {
+                        ImageFormat::Ppm => line.extend([r, g, b]),
+                        ImageFormat::Pgm => line.push(
+                            ((r as u32 * 299 + g as u32 * 587 + b as u32 * 114) / 1000) as u8,
+                        ),
+                    }
+                }
+            }
+            for _ in 0..self.scale {
+                writer.write_all(&line)?;
+            }
+        }
+        writer.flush()?;
+        self.count += 1;
+        Ok(())
+    }
+}
+
+// Writes every frame as plain text, each preceded by its number
+pub struct FrameLog<W: Write + Send> {
+    writer: W,
+    count: usize,
+}
+
+impl<W: Write + Send> FrameLog<W> {
+    pub fn new(writer: W) -> FrameLog<W> {
+        FrameLog { writer, count: 0 }
+    }
+}
+
+impl<W: Write + Send> FrameSink for FrameLog<W> {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        writeln!(self.writer, "Frame {}", self.count)?;
+        writeln!(self.writer, "{frame}")?;
+        self.count += 1;
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+pub fn install(sink: Box<dyn FrameSink>) {
+    *SINK.lock().unwrap() = Some(Installed { sink, error: None });
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+// Removes the sink, returning the first error it ran into
+pub fn finish() -> io::Result<()> {
+    ENABLED.store(false, Ordering::Relaxed);
+    let Some(mut installed) = SINK.lock().unwrap().take() else {
+        return Ok(());
+    };
+    if let Some(error) = installed.error {
+        return Err(error);
+    }
+    installed.sink.finish()
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+pub fn frame(build: impl FnOnce() -> Frame) {
+    if !enabled() {
+        return;
+    }
+    let frame = build();
+    let mut guard = SINK.lock().unwrap();
+    let Some(installed) = guard.as_mut() else {
+        return;
+    };
+    if installed.error.is_none() {
+        if let Err(error) = installed.sink.frame(&frame) {
+            installed.error = Some(error);
+        }
+    }
+}
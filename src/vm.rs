@@ -0,0 +1,360 @@
+use std::fmt::Display;
+
+use crate::{parse, SolveError};
+
+// The 3-bit computer from 2024 day 17
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Opcode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl Opcode {
+    pub const ALL: [Opcode; 8] = [
+        Opcode::Adv,
+        Opcode::Bxl,
+        Opcode::Bst,
+        Opcode::Jnz,
+        Opcode::Bxc,
+        Opcode::Out,
+        Opcode::Bdv,
+        Opcode::Cdv,
+    ];
+
+    // Only the low 3 bits are used, as every value in a program is a 3-bit number
+    pub fn from_bits(bits: u8) -> Opcode {
+        Opcode::ALL[(bits & 7) as usize]
+    }
+
+    pub fn mnemonic(self) -> &'static str {
+        match self {
+            Opcode::Adv => "adv",
+            Opcode::Bxl => "bxl",
+            Opcode::Bst => "bst",
+            Opcode::Jnz => "jnz",
+            Opcode::Bxc => "bxc",
+            Opcode::Out => "out",
+            Opcode::Bdv => "bdv",
+            Opcode::Cdv => "cdv",
+        }
+    }
+
+    // The other instructions use their operand as a literal, which `bxc` ignores
+    pub fn takes_combo(self) -> bool {
+        matches!(
+            self,
+            Opcode::Adv | Opcode::Bst | Opcode::Out | Opcode::Bdv | Opcode::Cdv
+        )
+    }
+}
+
+impl Display for Opcode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.mnemonic())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Registers {
+    pub a: usize,
+    pub b: usize,
+    pub c: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Executed(Opcode),
+    Output(u8),
+    Halted,
+}
+
+// One executed instruction with the registers around it, as recorded by `Vm::step_traced`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceEntry {
+    pub step: usize,
+    pub ip: usize,
+    pub opcode: Opcode,
+    pub operand: u8,
+    pub before: Registers,
+    pub after: Registers,
+    pub output: Option<u8>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Vm {
+    pub registers: Registers,
+    ip: usize,
+    program: Vec<u8>,
+    output: Vec<u8>,
+    steps: usize,
+}
+
+impl Vm {
+    pub fn new(registers: Registers, program: Vec<u8>) -> Vm {
+        Vm {
+            registers,
+            ip: 0,
+            program,
+            output: Vec::new(),
+            steps: 0,
+        }
+    }
+
+    // Reads the registers and program in the puzzle's "Register A: 729" format
+    pub fn parse(input: &str) -> Result<Vm, SolveError> {
+        let headers = parse::Headers::parse(input, input)?;
+        let registers = Registers {
+            a: headers.get("Register A")?,
+            b: headers.get("Register B")?,
+            c: headers.get("Register C")?,
+        };
+        let ops = headers.raw("Program")?;
+        let program: Vec<u8> = parse::list(input, ops, ",")?;
+        if let Some((op, value)) = ops.split(',').zip(&program).find(|(_, value)| **value > 7) {
+            return Err(SolveError::at_token(
+                input,
+                op,
+                format!("{value} is not a 3-bit number"),
+            ));
+        }
+        Ok(Vm::new(registers, program))
+    }
+
+    pub fn program(&self) -> &[u8] {
+        &self.program
+    }
+
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    pub fn output(&self) -> &[u8] {
+        &self.output
+    }
+
+    // Instructions executed so far, not counting the final `Step::Halted`
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    // The program halts when the instruction pointer leaves it, including when only the
+    // opcode of the last instruction is left
+    pub fn is_halted(&self) -> bool {
+        self.ip + 1 >= self.program.len()
+    }
+
+    // Starts over with `registers`, keeping the program
+    pub fn reset(&mut self, registers: Registers) {
+        self.registers = registers;
+        self.ip = 0;
+        self.output.clear();
+        self.steps = 0;
+    }
+
+    fn combo(&self, operand: u8) -> Result<usize, SolveError> {
+        match operand {
+            0..=3 => Ok(operand as usize),
+            4 => Ok(self.registers.a),
+            5 => Ok(self.registers.b),
+            6 => Ok(self.registers.c),
+            _ => Err(SolveError::new(format!(
+                "combo operand {operand} at instruction {} is reserved",
+                self.ip
+            ))),
+        }
+    }
+
+    // `a` divided by 2 to the power of the combo operand, which is 0 once the shift is too big
+    fn divide(&self, operand: u8) -> Result<usize, SolveError> {
+        let shift = self.combo(operand)?;
+        Ok(u32::try_from(shift)
+            .ok()
+            .and_then(|shift| self.registers.a.checked_shr(shift))
+            .unwrap_or(0))
+    }
+
+    pub fn step(&mut self) -> Result<Step, SolveError> {
+        if self.is_halted() {
+            return Ok(Step::Halted);
+        }
+        let opcode = Opcode::from_bits(self.program[self.ip]);
+        let operand = self.program[self.ip + 1];
+        let mut next_ip = self.ip + 2;
+        let mut step = Step::Executed(opcode);
+        match opcode {
+            Opcode::Adv => self.registers.a = self.divide(operand)?,
+            Opcode::Bxl => self.registers.b ^= operand as usize,
+            Opcode::Bst => self.registers.b = self.combo(operand)? % 8,
+            Opcode::Jnz => {
+                if self.registers.a != 0 {
+                    next_ip = operand as usize;
+                }
+            }
+            Opcode::Bxc => self.registers.b ^= self.registers.c,
+            Opcode::Out => {
+                let value = (self.combo(operand)? % 8) as u8;
+                self.output.push(value);
+                step = Step::Output(value);
+            }
+            Opcode::Bdv => self.registers.b = self.divide(operand)?,
+            Opcode::Cdv => self.registers.c = self.divide(operand)?,
+        }
+        self.ip = next_ip;
+        self.steps += 1;
+        Ok(step)
+    }
+
+    // Like `step`, returning what the instruction did, or `None` once the program has halted
+    pub fn step_traced(&mut self) -> Result<Option<TraceEntry>, SolveError> {
+        if self.is_halted() {
+            return Ok(None);
+        }
+        let (step, ip, before) = (self.steps, self.ip, self.registers);
+        let (opcode, operand) = (Opcode::from_bits(self.program[ip]), self.program[ip + 1]);
+        let output = match self.step()? {
+            Step::Output(value) => Some(value),
+            _ => None,
+        };
+        Ok(Some(TraceEntry {
+            step,
+            ip,
+            opcode,
+            operand,
+            before,
+            after: self.registers,
+            output,
+        }))
+    }
+
+    pub fn run(&mut self) -> Result<&[u8], SolveError> {
+        while self.step()? != Step::Halted {}
+        Ok(&self.output)
+    }
+
+    // Like `run`, but gives up on programs that are still running after `max_steps` instructions
+    pub fn run_with_limit(&mut self, max_steps: usize) -> Result<&[u8], SolveError> {
+        while !self.is_halted() {
+            if self.steps >= max_steps {
+                return Err(SolveError::new(format!(
+                    "program did not halt within {max_steps} steps"
+                )));
+            }
+            self.step()?;
+        }
+        Ok(&self.output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn machine(a: usize, b: usize, c: usize, program: &[u8]) -> Vm {
+        Vm::new(Registers { a, b, c }, program.to_vec())
+    }
+
+    #[test]
+    fn parses_registers_and_program() {
+        let input = "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0";
+        let vm = Vm::parse(input).unwrap();
+        assert_eq!(vm.registers, Registers { a: 729, b: 0, c: 0 });
+        assert_eq!(vm.program(), [0, 1, 5, 4, 3, 0]);
+        let input = "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,8";
+        let err = Vm::parse(input).unwrap_err();
+        assert_eq!(err.message, "8 is not a 3-bit number");
+        assert_eq!((err.line, err.column), (Some(5), Some(12)));
+    }
+
+    #[test]
+    fn divisions_shift_a_into_their_register() {
+        for (opcode, register) in [(0, 0), (6, 1), (7, 2)] {
+            let mut vm = machine(729, 2, 0, &[opcode, 5]);
+            assert_eq!(
+                vm.step().unwrap(),
+                Step::Executed(Opcode::from_bits(opcode))
+            );
+            let registers = vm.registers;
+            assert_eq!([registers.a, registers.b, registers.c][register], 729 >> 2);
+        }
+        // Shifting by more bits than a register holds leaves 0
+        let mut vm = machine(729, 0, 100, &[0, 6]);
+        vm.step().unwrap();
+        assert_eq!(vm.registers.a, 0);
+    }
+
+    #[test]
+    fn bitwise_opcodes() {
+        let mut vm = machine(0, 29, 0, &[1, 7]);
+        vm.step().unwrap();
+        assert_eq!(vm.registers.b, 26);
+        let mut vm = machine(0, 2024, 43690, &[4, 0]);
+        vm.step().unwrap();
+        assert_eq!(vm.registers.b, 44354);
+        let mut vm = machine(0, 0, 9, &[2, 6]);
+        vm.step().unwrap();
+        assert_eq!(vm.registers.b, 1);
+    }
+
+    #[test]
+    fn jnz_only_jumps_when_a_is_not_zero() {
+        let mut vm = machine(0, 0, 0, &[3, 4, 5, 0]);
+        vm.step().unwrap();
+        assert_eq!(vm.ip(), 2);
+        let mut vm = machine(1, 0, 0, &[3, 4, 5, 0, 5, 4]);
+        vm.step().unwrap();
+        assert_eq!(vm.ip(), 4);
+    }
+
+    #[test]
+    fn runs_until_halted() {
+        let mut vm = machine(10, 0, 0, &[5, 0, 5, 1, 5, 4]);
+        assert_eq!(vm.run().unwrap(), [0, 1, 2]);
+        assert_eq!(vm.step().unwrap(), Step::Halted);
+        let mut vm = machine(2024, 0, 0, &[0, 1, 5, 4, 3, 0]);
+        assert_eq!(vm.run().unwrap(), [4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]);
+        assert_eq!(vm.registers.a, 0);
+        // A lone opcode at the end has no operand, so the program halts before it
+        let mut vm = machine(0, 0, 0, &[5, 1, 5]);
+        assert_eq!(vm.run().unwrap(), [1]);
+    }
+
+    #[test]
+    fn combo_operand_7_is_an_error() {
+        let mut vm = machine(0, 0, 0, &[5, 1, 5, 7]);
+        vm.step().unwrap();
+        let err = vm.step().unwrap_err();
+        assert_eq!(err.message, "combo operand 7 at instruction 2 is reserved");
+        // Literal operands can be 7
+        let mut vm = machine(0, 0, 0, &[1, 7]);
+        assert!(vm.run().is_ok());
+    }
+
+    #[test]
+    fn step_limit_stops_endless_programs() {
+        let mut vm = machine(1, 0, 0, &[3, 0]);
+        let err = vm.run_with_limit(100).unwrap_err();
+        assert_eq!(err.message, "program did not halt within 100 steps");
+        assert_eq!(vm.steps(), 100);
+        let mut vm = machine(8, 0, 0, &[0, 3, 3, 0]);
+        assert!(vm.run_with_limit(4).is_ok());
+    }
+
+    #[test]
+    fn traced_steps_record_registers() {
+        let mut vm = machine(10, 0, 0, &[0, 1, 5, 4]);
+        let entry = vm.step_traced().unwrap().unwrap();
+        assert_eq!((entry.step, entry.ip, entry.opcode), (0, 0, Opcode::Adv));
+        assert_eq!((entry.before.a, entry.after.a, entry.output), (10, 5, None));
+        let entry = vm.step_traced().unwrap().unwrap();
+        assert_eq!(entry.output, Some(5));
+        assert_eq!(vm.step_traced().unwrap(), None);
+        vm.reset(Registers { a: 3, b: 0, c: 0 });
+        assert_eq!((vm.ip(), vm.steps(), vm.output()), (0, 0, &[][..]));
+    }
+}
@@ -0,0 +1,124 @@
+use std::{
+    fs,
+    path::PathBuf,
+    process::{Command, Output},
+};
+
+// A test directory holding only the inputs given, which is removed again when dropped
+struct TestDir(PathBuf);
+
+impl TestDir {
+    fn new(name: &str, inputs: &[(&str, &str)]) -> TestDir {
+        let dir = std::env::temp_dir().join(format!("aoc-cli-{name}-{}", std::process::id()));
+        let year_dir = dir.join("2024");
+        fs::create_dir_all(&year_dir).unwrap();
+        for (file, contents) in inputs {
+            fs::write(year_dir.join(file), contents).unwrap();
+        }
+        TestDir(dir)
+    }
+
+    fn run(&self, args: &[&str]) -> Output {
+        Command::new(env!("CARGO_BIN_EXE_advent-of-code-2024"))
+            .args(args)
+            .arg("--test-dir")
+            .arg(&self.0)
+            .output()
+            .unwrap()
+    }
+}
+
+impl Drop for TestDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+// Day 22's example, which needs about 1 MiB for its sequence counts
+const DAY22_EXAMPLE: &str = "1\n2\n3\n2024\n";
+
+#[test]
+fn day22_runs_under_a_timeout() {
+    let dir = TestDir::new("timeout", &[("22", DAY22_EXAMPLE)]);
+    let output = dir.run(&["--day", "22", "--part", "2", "--timeout", "30"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{output:?}");
+    assert!(stdout.lines().any(|line| line.trim() == "23"), "{stdout}");
+}
+
+#[test]
+fn day22_runs_on_pool_workers() {
+    let dir = TestDir::new("jobs", &[("22", DAY22_EXAMPLE), ("22.copy", DAY22_EXAMPLE)]);
+    for args in [
+        &["--all", "--jobs", "4", "--part", "2"][..],
+        &["--all", "--jobs", "4", "--part", "2", "--timeout", "60"][..],
+    ] {
+        let output = dir.run(args);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(output.status.success(), "{output:?}");
+        let rows: Vec<&str> = stdout
+            .lines()
+            .filter(|line| line.split_whitespace().take(2).eq(["22", "default"]))
+            .chain(stdout.lines().filter(|line| line.contains(" copy ")))
+            .collect();
+        assert_eq!(rows.len(), 2, "{stdout}");
+        assert!(rows
+            .iter()
+            .all(|row| row.contains(" 23 ") && row.ends_with("ok")));
+    }
+}
+
+#[test]
+fn unknown_params_name_a_day_without_any() {
+    let dir = TestDir::new("params", &[("1", "3   4\n4   3\n")]);
+    let output = dir.run(&["--day", "1", "--param", "width=11"]);
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr).trim(),
+        "Unknown parameter 'width', day 1 takes no parameters"
+    );
+}
+
+#[test]
+fn help_documents_every_flag() {
+    for (args, flags) in [
+        (
+            &["--help"][..],
+            &["--input-file", "--jobs", "--timeout", "--warn-input"][..],
+        ),
+        (
+            &["trace", "--help"],
+            &["--register-a", "--trace-output", "--max-steps"],
+        ),
+        (
+            &["debug", "--help"],
+            &["--register-a", "--break", "--max-steps"],
+        ),
+    ] {
+        let output = Command::new(env!("CARGO_BIN_EXE_advent-of-code-2024"))
+            .args(args)
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(output.status.success(), "{output:?}");
+        // Help is separated from the name column by at least two spaces, and comes before
+        // clap's `[default: ...]`
+        for line in stdout
+            .lines()
+            .filter(|line| line.trim_start().starts_with('-'))
+        {
+            let help = line
+                .trim()
+                .split("  ")
+                .map(str::trim)
+                .find(|part| !part.is_empty() && !part.starts_with('-'));
+            assert!(
+                help.is_some_and(|help| !help.starts_with('[')),
+                "{line} has no help"
+            );
+        }
+        for flag in flags {
+            assert!(stdout.contains(flag), "{flag} is missing from\n{stdout}");
+        }
+    }
+}